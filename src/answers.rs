@@ -0,0 +1,230 @@
+//! Known-answer store used to catch regressions on the real inputs
+//!
+//! Accepted answers live in `Input/answers.toml`, one table per day:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 1150
+//! part2 = 6738
+//! ```
+//!
+//! Only this small subset of TOML is read and written: `[dayNN]` headers,
+//! `partN = <integer>` or `partN = "<text>"` entries, and `#` comments.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::toml_lite::{self, Line, Value};
+use crate::{Answer, AocError, Context, Day, Part};
+
+/// The default location of the answer store
+pub fn answers_path() -> PathBuf {
+    PathBuf::from("Input/answers.toml")
+}
+
+/// Accepted answers keyed by day and part
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u8, Part), Answer>,
+}
+
+impl AnswerStore {
+    /// Load the store from `path`, or an empty store if the file doesn't exist
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|(line, message)| AocError::Malformed {
+                path: path.to_path_buf(),
+                line,
+                message,
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AocError::from_io(path, err)),
+        }
+    }
+
+    /// Write the store to `path`, creating its directory if needed
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), AocError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|err| AocError::from_io(dir, err))?;
+        }
+        fs::write(path, self.to_toml()).map_err(|err| AocError::from_io(path, err))
+    }
+
+    /// Parse the store's text, returning the 1-based line and message on error
+    pub fn parse(text: &str) -> Result<Self, (usize, String)> {
+        let mut store = Self::default();
+        let mut day = None;
+        for (line_no, line) in toml_lite::parse(text)? {
+            match line {
+                Line::Table(name) => {
+                    day = Some(
+                        name.strip_prefix("day")
+                            .and_then(|n| n.parse::<u8>().ok())
+                            .ok_or_else(|| {
+                                (line_no, format!("expected [dayNN], found [{}]", name))
+                            })?,
+                    );
+                }
+                Line::Entry(key, value) => {
+                    let day = day
+                        .ok_or_else(|| (line_no, "entry outside of a [dayNN] table".to_string()))?;
+                    let part = key
+                        .strip_prefix("part")
+                        .and_then(|n| n.parse::<u8>().ok())
+                        .and_then(Part::from_number)
+                        .ok_or_else(|| (line_no, format!("unknown key '{}'", key)))?;
+                    let answer = match value {
+                        Value::Integer(n) => Answer::Number(n),
+                        Value::String(s) => Answer::Text(s),
+                    };
+                    store.answers.insert((day, part), answer);
+                }
+            }
+        }
+        Ok(store)
+    }
+
+    /// Render the store in the same format [`AnswerStore::parse`] reads
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+        let mut current_day = None;
+        for (&(day, part), answer) in &self.answers {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    out.push('\n');
+                }
+                let _ = writeln!(out, "[day{:02}]", day);
+                current_day = Some(day);
+            }
+            let value = match answer {
+                Answer::Number(n) => n.to_string(),
+                Answer::Text(s) => toml_lite::quote(s),
+            };
+            let _ = writeln!(out, "part{} = {}", part, value);
+        }
+        out
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+}
+
+/// The outcome of checking one part against the store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// The solver reproduced the accepted answer
+    Pass,
+    /// The solver produced a different answer
+    Mismatch { expected: Answer, actual: Answer },
+    /// No accepted answer has been recorded yet
+    Unknown { actual: Answer },
+}
+
+/// Solve the given parts of `day` and compare them with the store
+pub fn check_day(
+    day: &Day,
+    input: &str,
+    parts: &[Part],
+    store: &AnswerStore,
+) -> Vec<(Part, Check)> {
+    let parsed = day.parse(input);
+    let ctx = Context::default();
+    parts
+        .iter()
+        .map(|&part| {
+            let actual = day.solve(&parsed, part, &ctx);
+            let check = match store.get(day.number, part) {
+                Some(expected) if *expected == actual => Check::Pass,
+                Some(expected) => Check::Mismatch {
+                    expected: expected.clone(),
+                    actual,
+                },
+                None => Check::Unknown { actual },
+            };
+            (part, check)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STORE: &str = r#"# accepted answers
+[day01]
+part1 = 3
+part2 = 6 # comment
+
+[day07]
+part1 = "ab#c"
+"#;
+
+    #[test]
+    fn test_parse_and_roundtrip() {
+        let store = AnswerStore::parse(STORE).unwrap();
+        assert_eq!(store.get(1, Part::One), Some(&Answer::Number(3)));
+        assert_eq!(store.get(1, Part::Two), Some(&Answer::Number(6)));
+        assert_eq!(
+            store.get(7, Part::One),
+            Some(&Answer::Text("ab#c".to_string()))
+        );
+        assert_eq!(store.get(7, Part::Two), None);
+
+        let text = store.to_toml();
+        assert_eq!(
+            text,
+            "[day01]\npart1 = 3\npart2 = 6\n\n[day07]\npart1 = \"ab#c\"\n"
+        );
+        assert_eq!(AnswerStore::parse(&text).unwrap(), store);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(AnswerStore::parse("part1 = 3").unwrap_err().0, 1);
+        assert_eq!(AnswerStore::parse("[day01]\npart3 = 3").unwrap_err().0, 2);
+        assert_eq!(AnswerStore::parse("[day01]\n\npart1 = x").unwrap_err().0, 3);
+        assert!(AnswerStore::parse("[week01]").is_err());
+    }
+
+    #[test]
+    fn test_check_day() {
+        let day = crate::days::get(1).unwrap();
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let mut store = AnswerStore::default();
+        store.insert(1, Part::One, Answer::Number(3));
+        store.insert(1, Part::Two, Answer::Number(7));
+
+        let checks = check_day(day, input, &Part::ALL, &store);
+        assert_eq!(checks[0], (Part::One, Check::Pass));
+        assert_eq!(
+            checks[1],
+            (
+                Part::Two,
+                Check::Mismatch {
+                    expected: Answer::Number(7),
+                    actual: Answer::Number(6)
+                }
+            )
+        );
+
+        let checks = check_day(day, input, &[Part::One], &AnswerStore::default());
+        assert_eq!(
+            checks,
+            vec![(
+                Part::One,
+                Check::Unknown {
+                    actual: Answer::Number(3)
+                }
+            )]
+        );
+    }
+}
@@ -0,0 +1,224 @@
+//! Statistical benchmarking of parsing and solving
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::Duration;
+
+use crate::memory::{self, AllocStats};
+use crate::{with_timers_silenced, Context, Day, Part, Timer, PARSE_LABEL};
+
+/// How many times to repeat the code under test
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts
+    pub warmup: u32,
+    /// Timed runs used for the statistics
+    pub iterations: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 25,
+        }
+    }
+}
+
+/// Summary statistics over the samples of one benchmark
+#[derive(Debug, Clone)]
+pub struct BenchStats {
+    /// The timer name the samples were taken with (e.g. `Part 1`)
+    pub name: String,
+    pub samples: Vec<Duration>,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    /// Size of the input processed by every iteration
+    pub input_bytes: usize,
+    /// What one run allocated, when allocations are counted
+    pub alloc: Option<AllocStats>,
+}
+
+impl BenchStats {
+    /// Compute statistics from raw samples
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty
+    pub fn from_samples(
+        name: impl Into<String>,
+        samples: Vec<Duration>,
+        input_bytes: usize,
+    ) -> Self {
+        assert!(
+            !samples.is_empty(),
+            "cannot compute statistics without samples"
+        );
+        let mut sorted = samples.clone();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            name: name.into(),
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            samples,
+            input_bytes,
+            alloc: None,
+        }
+    }
+
+    /// Runs per second, based on the mean
+    pub fn runs_per_sec(&self) -> f64 {
+        1.0 / self.mean.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+
+    /// Input megabytes processed per second, based on the mean
+    pub fn megabytes_per_sec(&self) -> f64 {
+        self.input_bytes as f64 / 1_000_000.0 * self.runs_per_sec()
+    }
+}
+
+impl fmt::Display for BenchStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "⏱️  {}: min {:?} | median {:?} | mean {:?} ± {:?} | {} runs | {:.0} runs/s, {:.2} MB/s",
+            self.name,
+            self.min,
+            self.median,
+            self.mean,
+            self.stddev,
+            self.samples.len(),
+            self.runs_per_sec(),
+            self.megabytes_per_sec()
+        )?;
+        if let Some(alloc) = self.alloc {
+            write!(f, " | {} per run", alloc)?;
+        }
+        Ok(())
+    }
+}
+
+/// Repeatedly time `f` under the given timer name
+///
+/// Each sample is measured with a [`Timer`]; timers created inside `f` are
+/// silenced so they don't print on every iteration.
+pub fn bench<R>(
+    name: &str,
+    config: BenchConfig,
+    input_bytes: usize,
+    mut f: impl FnMut() -> R,
+) -> BenchStats {
+    with_timers_silenced(|| {
+        for _ in 0..config.warmup {
+            black_box(f());
+        }
+        let mut alloc = None;
+        let samples = (0..config.iterations.max(1))
+            .map(|iteration| {
+                let timer = Timer::new(name);
+                let (_, run_alloc) = memory::measure(|| black_box(f()));
+                if iteration == 0 {
+                    alloc = run_alloc;
+                }
+                timer.elapsed()
+            })
+            .collect();
+        let mut stats = BenchStats::from_samples(name, samples, input_bytes);
+        stats.alloc = alloc;
+        stats
+    })
+}
+
+/// Benchmark parsing and the given parts of a day
+///
+/// Parts are benchmarked against a single parsed input, so their numbers
+/// exclude parsing just like a normal run.
+pub fn bench_day(day: &Day, input: &str, parts: &[Part], config: BenchConfig) -> Vec<BenchStats> {
+    let ctx = Context::default();
+    let mut results = vec![bench(PARSE_LABEL, config, input.len(), || {
+        day.parse(black_box(input))
+    })];
+    let parsed = with_timers_silenced(|| day.parse(input));
+    for &part in parts {
+        results.push(bench(&part.label(), config, input.len(), || {
+            day.solve(&parsed, part, &ctx)
+        }));
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = BenchStats::from_samples("Part 1", samples, 1_000);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2_500));
+        assert_eq!(stats.mean, Duration::from_micros(2_500));
+        // Population stddev of 1, 2, 3, 4 is sqrt(1.25)
+        assert!((stats.stddev.as_secs_f64() - 1.25_f64.sqrt() / 1000.0).abs() < 1e-9);
+        assert!((stats.runs_per_sec() - 400.0).abs() < 1e-6);
+        assert!((stats.megabytes_per_sec() - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bench_counts_iterations() {
+        let mut calls = 0;
+        let config = BenchConfig {
+            warmup: 2,
+            iterations: 5,
+        };
+        let stats = bench("Part 1", config, 0, || calls += 1);
+        assert_eq!(calls, 7);
+        assert_eq!(stats.samples.len(), 5);
+        assert_eq!(stats.name, "Part 1");
+    }
+
+    #[test]
+    fn test_bench_reports_allocations() {
+        let config = BenchConfig {
+            warmup: 0,
+            iterations: 3,
+        };
+        let stats = bench("Part 1", config, 0, || vec![0_u8; 2048]);
+        let alloc = stats.alloc.expect("the library tests count allocations");
+        assert_eq!((alloc.count, alloc.bytes), (1, 2048));
+        assert!(stats
+            .to_string()
+            .ends_with(" | 1 allocs, 2.0 KiB, peak 2.0 KiB per run"));
+    }
+
+    #[test]
+    fn test_bench_day_names() {
+        let day = crate::days::get(1).unwrap();
+        let config = BenchConfig {
+            warmup: 0,
+            iterations: 2,
+        };
+        let names: Vec<String> = bench_day(day, "L68\nR48\n", &Part::ALL, config)
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        assert_eq!(names, vec!["Parse", "Part 1", "Part 2"]);
+    }
+}
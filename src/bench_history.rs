@@ -0,0 +1,399 @@
+//! A local history of benchmark results, and comparing against a baseline
+//!
+//! Every `aoc bench` run appends one line per benchmark to
+//! `Input/bench_history.tsv`:
+//!
+//! ```text
+//! timestamp  day  name  rev  machine  input  label  min_ns  median_ns  mean_ns  stddev_ns  runs
+//! ```
+//!
+//! `rev` is the git revision the code was built from (with `-dirty` for
+//! uncommitted changes) and `label` is an optional baseline name. A later run
+//! can then be compared with the most recent result for the same day,
+//! benchmark, machine and input whose label or revision matches, and any
+//! benchmark whose median got slower by more than a threshold is flagged.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::bench::BenchStats;
+use crate::AocError;
+
+/// The default location of the benchmark history
+pub fn bench_history_path() -> PathBuf {
+    PathBuf::from("Input/bench_history.tsv")
+}
+
+/// Where a benchmark ran: the code revision and the machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchEnv {
+    pub rev: String,
+    pub machine: String,
+}
+
+impl BenchEnv {
+    /// The current git revision and this machine's name
+    ///
+    /// The machine is `AOC_MACHINE` if set, else the host name. Either part
+    /// is `unknown` if it can't be found out.
+    pub fn detect() -> Self {
+        Self {
+            rev: git_revision().unwrap_or_else(|| "unknown".to_string()),
+            machine: machine_name().unwrap_or_else(|| "unknown".to_string()),
+        }
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn git_revision() -> Option<String> {
+    let rev = git(&["rev-parse", "--short", "HEAD"])?;
+    // Benchmarks of uncommitted changes shouldn't pass for the commit
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty());
+    Some(if dirty { format!("{}-dirty", rev) } else { rev })
+}
+
+fn machine_name() -> Option<String> {
+    let name = std::env::var("AOC_MACHINE")
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .or_else(|| std::env::var("HOSTNAME").ok())?;
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// One benchmark result in the history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchRecord {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub day: u8,
+    /// The benchmark name, e.g. `Parse` or `Part 2`
+    pub name: String,
+    pub rev: String,
+    pub machine: String,
+    /// What was benchmarked on: an input path or a generated shape
+    pub input: String,
+    /// Baseline name given with `--save-baseline`, or empty
+    pub label: String,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub runs: u32,
+}
+
+impl BenchRecord {
+    /// A record of `stats`, timestamped now
+    pub fn new(day: u8, stats: &BenchStats, env: &BenchEnv, input: &str, label: &str) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        Self {
+            timestamp,
+            day,
+            name: stats.name.clone(),
+            rev: env.rev.clone(),
+            machine: env.machine.clone(),
+            // Tabs would split the field when the history is read back
+            input: input.replace('\t', " "),
+            label: label.replace('\t', " "),
+            min: stats.min,
+            median: stats.median,
+            mean: stats.mean,
+            stddev: stats.stddev,
+            runs: stats.samples.len() as u32,
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{:02}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.day,
+            self.name,
+            self.rev,
+            self.machine,
+            self.input,
+            self.label,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.stddev.as_nanos(),
+            self.runs
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self, &'static str> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [timestamp, day, name, rev, machine, input, label, min, median, mean, stddev, runs] =
+            fields[..]
+        else {
+            return Err("expected 12 tab-separated fields");
+        };
+        let nanos = |field: &str| {
+            field
+                .parse()
+                .map(Duration::from_nanos)
+                .map_err(|_| "invalid duration")
+        };
+        Ok(Self {
+            timestamp: timestamp.parse().map_err(|_| "invalid timestamp")?,
+            day: day.parse().map_err(|_| "invalid day")?,
+            name: name.to_string(),
+            rev: rev.to_string(),
+            machine: machine.to_string(),
+            input: input.to_string(),
+            label: label.to_string(),
+            min: nanos(min)?,
+            median: nanos(median)?,
+            mean: nanos(mean)?,
+            stddev: nanos(stddev)?,
+            runs: runs.parse().map_err(|_| "invalid run count")?,
+        })
+    }
+
+    /// Whether `selector` names this record's baseline label or revision
+    /// (a prefix of the revision is enough)
+    fn matches(&self, selector: &str) -> bool {
+        self.label == selector || (!selector.is_empty() && self.rev.starts_with(selector))
+    }
+}
+
+/// Every benchmark result recorded on this machine
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchHistory {
+    pub entries: Vec<BenchRecord>,
+}
+
+impl BenchHistory {
+    /// Load the history, or an empty one if the file doesn't exist
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(AocError::from_io(path, err)),
+        };
+
+        let mut entries = Vec::new();
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record = BenchRecord::from_line(line).map_err(|message| AocError::Malformed {
+                path: path.to_path_buf(),
+                line: idx + 1,
+                message: message.to_string(),
+            })?;
+            entries.push(record);
+        }
+        Ok(Self { entries })
+    }
+
+    /// Append records to the file at `path` and to this history
+    pub fn record(
+        &mut self,
+        path: impl AsRef<Path>,
+        records: Vec<BenchRecord>,
+    ) -> Result<(), AocError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|err| AocError::from_io(dir, err))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|err| AocError::from_io(path, err))?;
+        for record in records {
+            writeln!(file, "{}", record.to_line()).map_err(|err| AocError::from_io(path, err))?;
+            self.entries.push(record);
+        }
+        Ok(())
+    }
+
+    /// The latest result comparable to `current` whose label or revision
+    /// matches `selector`
+    pub fn baseline(&self, selector: &str, current: &BenchRecord) -> Option<&BenchRecord> {
+        self.entries.iter().rev().find(|entry| {
+            entry.day == current.day
+                && entry.name == current.name
+                && entry.machine == current.machine
+                && entry.input == current.input
+                && entry.matches(selector)
+        })
+    }
+}
+
+/// A benchmark's median now and in the baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub name: String,
+    /// The baseline's revision
+    pub baseline_rev: String,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Slowdown, in percent, above which this counts as a regression
+    pub threshold: f64,
+}
+
+impl Comparison {
+    pub fn new(baseline: &BenchRecord, current: &BenchRecord, threshold: f64) -> Self {
+        Self {
+            day: current.day,
+            name: current.name.clone(),
+            baseline_rev: baseline.rev.clone(),
+            baseline: baseline.median,
+            current: current.median,
+            threshold,
+        }
+    }
+
+    /// How much slower the current median is, in percent (negative if faster)
+    pub fn change_percent(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64().max(f64::MIN_POSITIVE);
+        (self.current.as_secs_f64() / baseline - 1.0) * 100.0
+    }
+
+    pub fn is_regression(&self) -> bool {
+        self.change_percent() > self.threshold
+    }
+
+    pub fn is_improvement(&self) -> bool {
+        self.change_percent() < -self.threshold
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.is_regression() {
+            "❌ slower"
+        } else if self.is_improvement() {
+            "✅ faster"
+        } else {
+            "≈ unchanged"
+        };
+        write!(
+            f,
+            "{}: median {:?} vs {:?} at {} ({:+.1}%) {}",
+            self.name,
+            self.current,
+            self.baseline,
+            self.baseline_rev,
+            self.change_percent(),
+            verdict
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, rev: &str, label: &str, median_ms: u64) -> BenchRecord {
+        BenchRecord {
+            timestamp: 1_700_000_000,
+            day: 2,
+            name: name.to_string(),
+            rev: rev.to_string(),
+            machine: "box".to_string(),
+            input: "Input/day02.txt".to_string(),
+            label: label.to_string(),
+            min: Duration::from_millis(median_ms - 1),
+            median: Duration::from_millis(median_ms),
+            mean: Duration::from_millis(median_ms),
+            stddev: Duration::from_micros(250),
+            runs: 25,
+        }
+    }
+
+    #[test]
+    fn test_record_and_reload() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-bench-{}", std::process::id()));
+        let path = dir.join("bench_history.tsv");
+        let _ = fs::remove_file(&path);
+
+        let mut history = BenchHistory::load(&path).unwrap();
+        assert!(history.entries.is_empty());
+        history
+            .record(
+                &path,
+                vec![
+                    record("Parse", "abc1234", "", 2),
+                    record("Part 2", "abc1234", "main", 30),
+                ],
+            )
+            .unwrap();
+        history
+            .record(&path, vec![record("Part 2", "def5678", "", 40)])
+            .unwrap();
+        assert_eq!(BenchHistory::load(&path).unwrap(), history);
+
+        fs::write(&path, "1\t02\tParse\n").unwrap();
+        let err = BenchHistory::load(&path).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("bench_history.tsv:1: expected 12 tab-separated fields"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_baseline_lookup() {
+        let history = BenchHistory {
+            entries: vec![
+                record("Part 2", "abc1234", "main", 30),
+                record("Part 2", "abc1234", "", 32),
+                record("Part 1", "abc1234", "main", 5),
+                record("Part 2", "def5678", "", 40),
+            ],
+        };
+        let current = record("Part 2", "0123abc-dirty", "", 36);
+        // The latest match wins, by label or by revision prefix
+        assert_eq!(
+            history.baseline("main", &current).unwrap().median,
+            Duration::from_millis(30)
+        );
+        assert_eq!(
+            history.baseline("abc", &current).unwrap().median,
+            Duration::from_millis(32)
+        );
+        assert!(history.baseline("fff", &current).is_none());
+
+        let other_machine = BenchRecord {
+            machine: "laptop".to_string(),
+            ..current.clone()
+        };
+        assert!(history.baseline("main", &other_machine).is_none());
+    }
+
+    #[test]
+    fn test_comparison_threshold() {
+        let baseline = record("Part 2", "abc1234", "main", 40);
+        let slower = Comparison::new(&baseline, &record("Part 2", "def", "", 46), 10.0);
+        assert!(slower.is_regression());
+        assert!((slower.change_percent() - 15.0).abs() < 1e-9);
+        assert!(slower.to_string().ends_with("(+15.0%) ❌ slower"));
+
+        let noise = Comparison::new(&baseline, &record("Part 2", "def", "", 42), 10.0);
+        assert!(!noise.is_regression() && !noise.is_improvement());
+        let faster = Comparison::new(&baseline, &record("Part 2", "def", "", 20), 10.0);
+        assert!(faster.is_improvement());
+        assert!(faster
+            .to_string()
+            .contains("vs 40ms at abc1234 (-50.0%) ✅ faster"));
+    }
+}
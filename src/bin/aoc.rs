@@ -0,0 +1,738 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use aoc2025::answers::{answers_path, check_day, AnswerStore, Check};
+use aoc2025::bench::{bench_day, BenchConfig};
+use aoc2025::bench_history::{bench_history_path, BenchEnv, BenchHistory, BenchRecord, Comparison};
+use aoc2025::cli::{flag_number, flag_value, DaySelection, RunArgs, STDIN};
+use aoc2025::config::Config;
+use aoc2025::fetch::{cached_input_path, fetch_input};
+use aoc2025::fixtures::{extract_examples, fixtures_path, puzzle_page_path, Fixtures};
+use aoc2025::generate::Shape;
+use aoc2025::rng::Rng;
+use aoc2025::scaffold::{create_day, module_path, NewDay};
+use aoc2025::submit::{history_path, submit_answer, SubmissionHistory, Verdict};
+use aoc2025::summary::{render, run_all, DayReport, Format, Status};
+use aoc2025::timing::{render_report, set_print_on_drop, spans_to_json, take_spans};
+use aoc2025::trace;
+use aoc2025::watch::{render_round, run_round, Watcher};
+use aoc2025::{
+    days, input_path, try_read_input_from_path, with_timers_silenced, Answer, AocError, Context,
+    Day, Part, Timer,
+};
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: aoc2025::memory::CountingAllocator = aoc2025::memory::CountingAllocator;
+
+const USAGE: &str = "\
+Usage: aoc <command> [options]
+
+Commands:
+  run <day> | --all    Solve one day, or every registered day
+  bench <day> | --all  Benchmark parsing and solving with repeated runs
+  check <day> | --all  Validate inputs and report every problem with its position
+  summary              Run every day in parallel and tabulate answers and timings
+  fetch <day> | --all  Download puzzle inputs into Input/ (never re-downloads)
+  submit <day> -p <n>  Solve a part (or take --answer) and submit it
+  verify [<day>]       Check answers against Input/answers.toml (all days by default)
+  accept <day>         Solve a day and record its answers in Input/answers.toml
+  new <day>            Generate the files for a new day from the template
+  examples <day>       Extract example inputs and answers from a saved puzzle page
+  generate <day>       Print a random input for a day to stdout
+  watch <day>          Re-run a day and its examples whenever its input or
+                       fixtures file changes (edits to the solution only take
+                       effect after rebuilding and restarting it)
+  list                 List the registered days
+  help                 Show this message
+
+Run options:
+  -p, --part <1|2>     Only solve the given part
+  -i, --input <path>   Read the input from <path> instead of Input/dayXX.txt
+                       (- reads stdin)
+  -t, --trace <filter> Print solver trace events to stderr, e.g. day01=debug or
+                       info,day04=trace (levels: off, info, debug, trace)
+  -v, --verbose        Same as --trace debug
+  --timings <format>   Timing report after the run: tree (default), json or off
+  --print-timers       Also print each timer as it finishes
+  -f, --format <fmt>   Print only the results, one record per day and part, as
+                       json, csv or markdown (a table per day) instead of text
+
+Missing inputs are downloaded automatically when a session token is set via
+AOC_SESSION or `session = \"...\"` in Input/config.toml.
+
+Submit options:
+  --answer <value>     Submit this answer instead of solving the part
+
+New options:
+  --title <name>       Puzzle title (default \"Untitled\")
+  --viz                Also generate a dayXX_viz visualization skeleton
+
+Examples options:
+  --page <path>        Saved puzzle page to read (default Input/dayXX.html)
+  --force              Replace an existing fixtures/dayXX.toml
+
+Generate options (bench also takes them, to benchmark on a generated input):
+  --shape <spec>       Size and shape of the input, e.g. steps=5000,magnitude=99
+                       (keys differ per day; unset keys keep the day's defaults)
+  --seed <n>           Random seed (default 1)
+
+Watch options (plus --part, --input and --trace):
+  --interval <ms>      How often to look for changes (default 500)
+
+Summary options (plus --part):
+  --jobs <n>           Days to run at once (default: one per CPU)
+  -f, --format <fmt>   text (the table, default), json, csv or markdown
+
+Bench options (plus --part and --input):
+  --warmup <n>         Untimed runs before sampling (default 3)
+  --iterations <n>     Timed runs per benchmark (default 25)
+  --baseline <name>    Compare medians with the latest results saved under this
+                       baseline name or git revision (prefix)
+  --threshold <pct>    Slowdown that counts as a regression (default 5)
+  --save-baseline <name>  Record the results under this baseline name
+  --no-record          Don't append the results to Input/bench_history.tsv";
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("run") => run(args),
+        Some("bench") => bench(args),
+        Some("summary") => summary(args),
+        Some("check") => RunArgs::parse(args).and_then(|run_args| check(&run_args)),
+        Some("fetch") => RunArgs::parse(args).and_then(|run_args| fetch(&run_args)),
+        Some("submit") => submit(args),
+        Some("verify") => verify(args),
+        Some("accept") => RunArgs::parse(args).and_then(|run_args| accept(&run_args)),
+        Some("new") => new_day(args),
+        Some("examples") => examples(args),
+        Some("generate") => generate(args),
+        Some("watch") => watch(args),
+        Some("list") => {
+            list();
+            Ok(())
+        }
+        Some("help") | Some("--help") | Some("-h") | None => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        Some(other) => Err(format!("Unknown command '{}'\n\n{}", other, USAGE)),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn list() {
+    for day in days::all() {
+        println!("Day {:02}: {}", day.number, day.title);
+    }
+}
+
+fn selected_days(args: &RunArgs) -> Result<Vec<&'static Day>, String> {
+    match args.days {
+        DaySelection::All => Ok(days::all().iter().collect()),
+        DaySelection::One(number) => days::get(number)
+            .map(|day| vec![day])
+            .ok_or_else(|| format!("Day {} is not implemented", number)),
+    }
+}
+
+/// Load the input for `day`, or `None` if it should be skipped
+fn load_input(day: &Day, args: &RunArgs) -> Result<Option<String>, String> {
+    match args.read_input(day.number) {
+        Ok(input) => Ok(Some(input)),
+        // Running everything should not stop at the first day without an input file
+        Err(err @ AocError::NotFound { .. }) if args.days == DaySelection::All => {
+            println!("Skipped: {}", err);
+            Ok(None)
+        }
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// How to show the collected timing spans at the end of a run
+enum TimingReport {
+    Tree,
+    Json,
+    Off,
+}
+
+fn run(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut report = TimingReport::Tree;
+    let mut timing_flags = false;
+    let mut format = Format::Text;
+    let args = RunArgs::parse_with(args, |flag, rest| {
+        match flag {
+            "--timings" => {
+                report = match flag_value(flag, rest.next())?.as_str() {
+                    "tree" => TimingReport::Tree,
+                    "json" => TimingReport::Json,
+                    "off" => TimingReport::Off,
+                    other => return Err(format!("Invalid timing report '{}'", other)),
+                };
+                timing_flags = true;
+            }
+            "--print-timers" => {
+                set_print_on_drop(true);
+                timing_flags = true;
+            }
+            "--format" | "-f" => format = flag_value(flag, rest.next())?.parse()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    trace::set_filter(args.trace.clone());
+    if format != Format::Text {
+        if timing_flags {
+            return Err("--format can't be combined with --timings or --print-timers".to_string());
+        }
+        return run_formatted(&args, format);
+    }
+    let ctx = Context::default();
+
+    for day in selected_days(&args)? {
+        println!("Day {:02}: {}", day.number, day.title);
+        let Some(input) = load_input(day, &args)? else {
+            continue;
+        };
+        let _timer = Timer::new(format!("Day {:02}", day.number));
+        let parsed = day.parse(&input);
+        for part in args.parts() {
+            println!("Part {}: {}", part, day.solve(&parsed, part, &ctx));
+        }
+    }
+
+    match report {
+        TimingReport::Tree => print!("\n{}", render_report(&take_spans())),
+        TimingReport::Json => println!("{}", spans_to_json(&take_spans())),
+        TimingReport::Off => {}
+    }
+    Ok(())
+}
+
+/// `run` for scripts: solve every selected day, then print only the results
+/// in `format`
+fn run_formatted(args: &RunArgs, format: Format) -> Result<(), String> {
+    let days = selected_days(args)?;
+    let reports = run_all(&days, &args.parts(), 1, |day| args.read_input(day.number));
+    print!("{}", render(&reports, &args.parts(), format));
+
+    // As in a text run, only a single day treats a missing input as an error
+    let failed: Vec<&DayReport> = reports
+        .iter()
+        .filter(|report| match report.status {
+            Status::Ok => false,
+            Status::MissingInput => args.days != DaySelection::All,
+            Status::Panicked(_) | Status::BadInput(_) => true,
+        })
+        .collect();
+    match failed.as_slice() {
+        [] => Ok(()),
+        [report] => Err(format!("Day {:02}: {}", report.day, report.status)),
+        _ => Err(format!("{} day(s) failed", failed.len())),
+    }
+}
+
+fn bench(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut config = BenchConfig::default();
+    let mut generated: Option<(Shape, u64)> = None;
+    let mut record = true;
+    let mut label = String::new();
+    let mut baseline: Option<String> = None;
+    let mut threshold = 5.0;
+    let args = RunArgs::parse_with(args, |flag, rest| {
+        match flag {
+            "--warmup" => config.warmup = flag_number(flag, rest.next())?,
+            "--iterations" => config.iterations = flag_number(flag, rest.next())?,
+            "--no-record" => record = false,
+            "--save-baseline" => label = flag_value(flag, rest.next())?,
+            "--baseline" => baseline = Some(flag_value(flag, rest.next())?),
+            "--threshold" => threshold = flag_number(flag, rest.next())?,
+            "--shape" => {
+                let shape = Shape::parse(&flag_value(flag, rest.next())?)?;
+                generated.get_or_insert((Shape::new(), 1)).0 = shape;
+            }
+            "--seed" => {
+                generated.get_or_insert((Shape::new(), 1)).1 = flag_number(flag, rest.next())?
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    trace::set_filter(args.trace.clone());
+    if generated.is_some() && args.input.is_some() {
+        return Err("--input can't be combined with --shape or --seed".to_string());
+    }
+    if !record && !label.is_empty() {
+        return Err("--save-baseline needs the results to be recorded".to_string());
+    }
+
+    let env = BenchEnv::detect();
+    let history_path = bench_history_path();
+    let mut history = BenchHistory::load(&history_path).map_err(|err| err.to_string())?;
+    let mut regressions = 0;
+    for day in selected_days(&args)? {
+        println!("Day {:02}: {}", day.number, day.title);
+        let input = match &generated {
+            Some((shape, seed)) => {
+                let input = day.generate(&mut Rng::new(*seed), shape)?;
+                println!(
+                    "Generated input: {} bytes (shape '{}', seed {})",
+                    input.len(),
+                    shape,
+                    seed
+                );
+                input
+            }
+            None => match load_input(day, &args)? {
+                Some(input) => input,
+                None => continue,
+            },
+        };
+        let input_name = match (&generated, &args.input) {
+            (Some((shape, seed)), _) => format!("generated:{}@{}", shape, seed),
+            (None, Some(path)) => path.display().to_string(),
+            (None, None) => input_path(day.number).display().to_string(),
+        };
+
+        let mut records = Vec::new();
+        for stats in bench_day(day, &input, &args.parts(), config) {
+            println!("{}", stats);
+            records.push(BenchRecord::new(
+                day.number,
+                &stats,
+                &env,
+                &input_name,
+                &label,
+            ));
+        }
+        if let Some(selector) = &baseline {
+            for current in &records {
+                match history.baseline(selector, current) {
+                    Some(base) => {
+                        let comparison = Comparison::new(base, current, threshold);
+                        regressions += usize::from(comparison.is_regression());
+                        println!("   {}", comparison);
+                    }
+                    None => println!("   {}: no result for baseline '{}'", current.name, selector),
+                }
+            }
+        }
+        if record {
+            history
+                .record(&history_path, records)
+                .map_err(|err| err.to_string())?;
+        }
+    }
+
+    if record {
+        println!(
+            "Recorded in {} ({} on {})",
+            history_path.display(),
+            env.rev,
+            env.machine
+        );
+    }
+    if regressions > 0 {
+        Err(format!(
+            "{} benchmark(s) slower than the baseline by more than {}%",
+            regressions, threshold
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn summary(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let args = std::iter::once("--all".to_string()).chain(args);
+    let mut jobs = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut format = Format::Text;
+    let args = RunArgs::parse_with(args, |flag, rest| {
+        match flag {
+            "--jobs" => jobs = flag_number(flag, rest.next())?,
+            "--format" | "-f" => format = flag_value(flag, rest.next())?.parse()?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    trace::set_filter(args.trace.clone());
+    if args.input.is_some() {
+        return Err("summary always runs every day on its cached input in Input/".to_string());
+    }
+
+    let days = selected_days(&args)?;
+    let reports = run_all(&days, &args.parts(), jobs, |day| {
+        try_read_input_from_path(input_path(day.number))
+    });
+    print!("{}", render(&reports, &args.parts(), format));
+
+    let panicked = reports
+        .iter()
+        .filter(|report| matches!(report.status, Status::Panicked(_)))
+        .count();
+    if panicked > 0 {
+        Err(format!("{} day(s) panicked", panicked))
+    } else {
+        Ok(())
+    }
+}
+
+fn check(args: &RunArgs) -> Result<(), String> {
+    trace::set_filter(args.trace.clone());
+    let mut problems = 0;
+    for day in selected_days(args)? {
+        let Some(input) = load_input(day, args)? else {
+            continue;
+        };
+        let path = args.input.clone().unwrap_or_else(|| input_path(day.number));
+        let errors = day.validate(&input);
+        for error in &errors {
+            println!(
+                "{}:{}:{}: {}",
+                path.display(),
+                error.line,
+                error.column,
+                error.message
+            );
+            if let Some(line) = input.lines().nth(error.line.saturating_sub(1)) {
+                let indent: String = line
+                    .chars()
+                    .take(error.column.saturating_sub(1))
+                    .map(|c| if c == '\t' { '\t' } else { ' ' })
+                    .collect();
+                println!("    {}\n    {}^", line, indent);
+            }
+        }
+        if errors.is_empty() {
+            println!("Day {:02}: ok", day.number);
+        } else {
+            println!("Day {:02}: {} problem(s)", day.number, errors.len());
+        }
+        problems += errors.len();
+    }
+
+    if problems > 0 {
+        Err(format!("{} problem(s) found", problems))
+    } else {
+        Ok(())
+    }
+}
+
+fn generate(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut shape = Shape::new();
+    let mut seed = 1;
+    let args = RunArgs::parse_with(args, |flag, rest| {
+        match flag {
+            "--shape" => shape = Shape::parse(&flag_value(flag, rest.next())?)?,
+            "--seed" => seed = flag_number(flag, rest.next())?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    if args.days == DaySelection::All || args.input.is_some() || !args.trace.is_off() {
+        return Err("generate takes a single day and prints the input to stdout".to_string());
+    }
+
+    for day in selected_days(&args)? {
+        print!("{}", day.generate(&mut Rng::new(seed), &shape)?);
+    }
+    Ok(())
+}
+
+fn watch(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut interval = 500;
+    let args = RunArgs::parse_with(args, |flag, rest| match flag {
+        "--interval" => {
+            interval = flag_number(flag, rest.next())?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    })?;
+    let DaySelection::One(number) = args.days else {
+        return Err("watch takes a single day".to_string());
+    };
+    let day = days::get(number).ok_or_else(|| format!("Day {} is not implemented", number))?;
+    if args.input.as_deref() == Some(Path::new(STDIN)) {
+        return Err("watch needs an input file to poll, not stdin".to_string());
+    }
+    trace::set_filter(args.trace.clone());
+
+    let input = args.input.clone().unwrap_or_else(|| input_path(day.number));
+    let fixtures = fixtures_path(day.number);
+    let source = module_path(day.number);
+    println!(
+        "Watching {} and {} (Ctrl-C to stop)",
+        input.display(),
+        fixtures.display()
+    );
+    let mut watcher = Watcher::new([input.clone(), fixtures.clone(), source.clone()]);
+    let mut previous = None;
+    loop {
+        println!("Day {:02}: {}", day.number, day.title);
+        // Silenced so the span tree doesn't grow with every round
+        let round = with_timers_silenced(|| {
+            run_round(
+                day,
+                try_read_input_from_path(&input),
+                Fixtures::load(&fixtures),
+                &args.parts(),
+            )
+        });
+        print!("{}", render_round(&round, previous.as_ref()));
+        previous = Some(round);
+
+        let changed = loop {
+            thread::sleep(Duration::from_millis(interval));
+            let mut changed = watcher.changed();
+            // This process runs the solver it was built with, so re-running
+            // after an edit to the solution would only repeat the old answers
+            if changed.contains(&source) {
+                println!(
+                    "\n{} changed: rebuild and restart `aoc watch` to run the new code",
+                    source.display()
+                );
+                changed.retain(|path| *path != source);
+            }
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        let names: Vec<String> = changed
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        println!("\n{} changed", names.join(" and "));
+    }
+}
+
+fn verify(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut args: Vec<String> = args.collect();
+    if args.is_empty() {
+        args.push("--all".to_string());
+    }
+    let args = RunArgs::parse(args)?;
+    trace::set_filter(args.trace.clone());
+    let store = AnswerStore::load(answers_path()).map_err(|err| err.to_string())?;
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for day in selected_days(&args)? {
+        let Some(input) = load_input(day, &args)? else {
+            continue;
+        };
+        let checks = with_timers_silenced(|| check_day(day, &input, &args.parts(), &store));
+        for (part, check) in checks {
+            print!("Day {:02} part {}: ", day.number, part);
+            match check {
+                Check::Pass => {
+                    passed += 1;
+                    println!("✅ pass");
+                }
+                Check::Mismatch { expected, actual } => {
+                    failed += 1;
+                    println!("❌ expected {}, got {}", expected, actual);
+                }
+                Check::Unknown { actual } => {
+                    unknown += 1;
+                    println!("❔ no accepted answer (got {})", actual);
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} without an accepted answer",
+        passed, failed, unknown
+    );
+    if failed > 0 {
+        Err(format!("{} answer(s) no longer match", failed))
+    } else {
+        Ok(())
+    }
+}
+
+fn accept(args: &RunArgs) -> Result<(), String> {
+    trace::set_filter(args.trace.clone());
+    if args.days == DaySelection::All || args.input.is_some() {
+        return Err("accept takes a single day and always uses its canonical input".to_string());
+    }
+    let path = answers_path();
+    let mut store = AnswerStore::load(&path).map_err(|err| err.to_string())?;
+    let ctx = Context::default();
+
+    for day in selected_days(args)? {
+        let Some(input) = load_input(day, args)? else {
+            continue;
+        };
+        let parsed = with_timers_silenced(|| day.parse(&input));
+        for part in args.parts() {
+            let answer = with_timers_silenced(|| day.solve(&parsed, part, &ctx));
+            println!("Day {:02} part {}: {}", day.number, part, answer);
+            store.insert(day.number, part, answer);
+        }
+    }
+    store.save(&path).map_err(|err| err.to_string())?;
+    println!("Saved to {}", path.display());
+    Ok(())
+}
+
+fn fetch(args: &RunArgs) -> Result<(), String> {
+    if args.input.is_some() || args.part.is_some() || !args.trace.is_off() {
+        return Err("fetch only takes a day or --all".to_string());
+    }
+    let config = Config::load().map_err(|err| err.to_string())?;
+    for day in selected_days(args)? {
+        let input = fetch_input(day.number, &config).map_err(|err| err.to_string())?;
+        println!(
+            "Day {:02}: {} ({} lines)",
+            day.number,
+            cached_input_path(&config, day.number).display(),
+            input.lines().count()
+        );
+    }
+    Ok(())
+}
+
+fn submit(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut answer = None;
+    let args = RunArgs::parse_with(args, |flag, rest| match flag {
+        "--answer" => {
+            answer = Some(flag_value(flag, rest.next())?);
+            Ok(true)
+        }
+        _ => Ok(false),
+    })?;
+    trace::set_filter(args.trace.clone());
+    let (DaySelection::One(number), Some(part)) = (args.days, args.part) else {
+        return Err("submit needs a single day and --part".to_string());
+    };
+    let day = days::get(number).ok_or_else(|| format!("Day {} is not implemented", number))?;
+
+    let answer: Answer = match answer {
+        Some(text) => match text.parse::<i64>() {
+            Ok(n) => n.into(),
+            Err(_) => text.into(),
+        },
+        None => {
+            let Some(input) = load_input(day, &args)? else {
+                return Ok(());
+            };
+            let ctx = Context::default();
+            with_timers_silenced(|| day.run(&input, part, &ctx))
+        }
+    };
+    println!("Day {:02} part {}: submitting {}", day.number, part, answer);
+
+    let config = Config::load().map_err(|err| err.to_string())?;
+    let path = history_path();
+    let mut history = SubmissionHistory::load(&path).map_err(|err| err.to_string())?;
+    let verdict = submit_answer(&config, &mut history, &path, day.number, part, &answer)
+        .map_err(|err| err.to_string())?;
+    println!("{}", verdict);
+
+    if verdict == Verdict::Correct {
+        let store_path = answers_path();
+        let mut store = AnswerStore::load(&store_path).map_err(|err| err.to_string())?;
+        store.insert(day.number, part, answer);
+        store.save(&store_path).map_err(|err| err.to_string())?;
+        println!("Recorded in {}", store_path.display());
+    }
+    Ok(())
+}
+
+fn new_day(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut title = "Untitled".to_string();
+    let mut viz = false;
+    let args = RunArgs::parse_with(args, |flag, rest| {
+        match flag {
+            "--title" => title = flag_value(flag, rest.next())?,
+            "--viz" => viz = true,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    let DaySelection::One(day) = args.days else {
+        return Err("new takes a single day".to_string());
+    };
+    if args.part.is_some() || args.input.is_some() || !args.trace.is_off() {
+        return Err("new only takes a day, --title and --viz".to_string());
+    }
+
+    let new_day = NewDay { day, title, viz };
+    for path in create_day(Path::new("."), &new_day).map_err(|err| err.to_string())? {
+        println!("Wrote {}", path.display());
+    }
+    println!(
+        "Next: save the puzzle page as {}, run `aoc examples {}` and remove the `#[ignore]`s in {}",
+        puzzle_page_path(day).display(),
+        day,
+        new_day.module_path().display()
+    );
+    Ok(())
+}
+
+fn examples(args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut page = None;
+    let mut force = false;
+    let args = RunArgs::parse_with(args, |flag, rest| {
+        match flag {
+            "--page" => page = Some(PathBuf::from(flag_value(flag, rest.next())?)),
+            "--force" => force = true,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    })?;
+    let DaySelection::One(day) = args.days else {
+        return Err("examples takes a single day".to_string());
+    };
+    if args.part.is_some() || args.input.is_some() || !args.trace.is_off() {
+        return Err("examples only takes a day, --page and --force".to_string());
+    }
+
+    let page = page.unwrap_or_else(|| puzzle_page_path(day));
+    let html =
+        fs::read_to_string(&page).map_err(|err| AocError::from_io(&page, err).to_string())?;
+    let fixtures = extract_examples(&html);
+    if fixtures.is_empty() {
+        return Err(format!("No example found in {}", page.display()));
+    }
+    for part in Part::ALL {
+        let Some(example) = fixtures.get(part) else {
+            println!("Part {}: no example", part);
+            continue;
+        };
+        let answer = match &example.answer {
+            Some(answer) => answer.to_string(),
+            None => "not found, fill it in by hand".to_string(),
+        };
+        println!(
+            "Part {}: {} input lines, answer {}",
+            part,
+            example.input.lines().count(),
+            answer
+        );
+    }
+
+    let path = fixtures_path(day);
+    let saved = if force {
+        fixtures.save(&path)
+    } else {
+        fixtures.save_new(&path)
+    };
+    saved.map_err(|err| match err {
+        AocError::AlreadyExists { .. } => {
+            format!("{} (pass --force to replace it)", err)
+        }
+        err => err.to_string(),
+    })?;
+    println!("Saved to {}", path.display());
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use aoc2025::read_input;
+use aoc2025::{input_path, try_read_input};
 
 use super::types::{DialSimulation, Step, UiState};
 
@@ -37,10 +37,9 @@ pub fn parse_steps(input: &str) -> Result<Vec<Step>, String> {
 }
 
 pub fn try_load_default(sim: &mut DialSimulation) -> Result<(), String> {
-    let input = std::panic::catch_unwind(|| read_input(1))
-        .map_err(|_| "Failed to read Input/day01.txt".to_string())?;
+    let input = try_read_input(1).map_err(|err| err.to_string())?;
     let steps = parse_steps(&input)?;
-    sim.reset_with_steps(steps, Some(PathBuf::from("Input/day01.txt")));
+    sim.reset_with_steps(steps, Some(input_path(1)));
     Ok(())
 }
 
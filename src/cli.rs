@@ -0,0 +1,340 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use crate::timing::{render_report, take_spans};
+use crate::trace::{Level, TraceFilter};
+use crate::{
+    days, try_read_input, try_read_input_from_path, try_read_stdin, AocError, Context, Part,
+};
+
+/// The `--input` value that means "read standard input"
+pub const STDIN: &str = "-";
+
+/// Which days a command applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaySelection {
+    One(u8),
+    All,
+}
+
+/// Options for running one or all days, as given on the command line
+///
+/// Accepts `<day> | --all`, `--part <1|2>`, `--input <path>` (`-` for stdin),
+/// `--trace <filter>` (repeatable) and `--verbose` (short for `--trace debug`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunArgs {
+    pub days: DaySelection,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub trace: TraceFilter,
+}
+
+impl RunArgs {
+    /// Parse the arguments that follow a subcommand name
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self::parse_with(args, |_, _| Ok(false))
+    }
+
+    /// Parse the arguments, offering unrecognised options to `extra` first
+    ///
+    /// `extra` receives the option and the remaining arguments (to take a
+    /// value from), and returns whether it handled the option.
+    pub fn parse_with<I, F>(args: I, mut extra: F) -> Result<Self, String>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        F: FnMut(&str, &mut dyn Iterator<Item = String>) -> Result<bool, String>,
+    {
+        let mut day = None;
+        let mut all = false;
+        let mut part = None;
+        let mut input = None;
+        let mut trace = TraceFilter::off();
+
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" | "-a" => all = true,
+                "--verbose" | "-v" => trace = TraceFilter::all(Level::Debug),
+                "--trace" | "-t" => trace.add(&flag_value(&arg, args.next())?)?,
+                "--part" | "-p" => part = Some(parse_part(&flag_value(&arg, args.next())?)?),
+                "--input" | "-i" => input = Some(PathBuf::from(flag_value(&arg, args.next())?)),
+                flag if flag.starts_with('-') => {
+                    if !extra(flag, &mut args)? {
+                        return Err(format!("Unknown option '{}'", flag));
+                    }
+                }
+                value => {
+                    if day.is_some() {
+                        return Err(format!("Unexpected argument '{}'", value));
+                    }
+                    day = Some(parse_day(value)?);
+                }
+            }
+        }
+
+        let days = match (day, all) {
+            (Some(day), false) => DaySelection::One(day),
+            (None, true) => DaySelection::All,
+            (Some(_), true) => return Err("Give either a day or --all, not both".to_string()),
+            (None, false) => return Err("Missing day (or --all)".to_string()),
+        };
+        if days == DaySelection::All && input.is_some() {
+            return Err("--input can only be used with a single day".to_string());
+        }
+
+        Ok(Self {
+            days,
+            part,
+            input,
+            trace,
+        })
+    }
+
+    /// The parts selected with `--part`, or both parts if none was given
+    pub fn parts(&self) -> Vec<Part> {
+        selected_parts(self.part)
+    }
+
+    /// Read the input for `day` as selected with `--input`
+    pub fn read_input(&self, day: u8) -> Result<String, AocError> {
+        read_input(day, self.input.as_deref())
+    }
+}
+
+/// Options for a single day's binary, e.g. `day04 --part 2 -`
+///
+/// Accepts `--part <1|2>`, `--input <path>`, a lone `-` (short for
+/// `--input -`, reading stdin) and `--help`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayArgs {
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub help: bool,
+}
+
+impl DayArgs {
+    /// Parse the arguments that follow the binary's name
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            let input = match arg.as_str() {
+                "--help" | "-h" => {
+                    parsed.help = true;
+                    continue;
+                }
+                "--part" | "-p" => {
+                    parsed.part = Some(parse_part(&flag_value(&arg, args.next())?)?);
+                    continue;
+                }
+                "--input" | "-i" => flag_value(&arg, args.next())?,
+                STDIN => arg,
+                flag if flag.starts_with('-') => return Err(format!("Unknown option '{}'", flag)),
+                value => return Err(format!("Unexpected argument '{}'", value)),
+            };
+            if parsed.input.is_some() {
+                return Err("Give at most one input".to_string());
+            }
+            parsed.input = Some(PathBuf::from(input));
+        }
+        Ok(parsed)
+    }
+
+    /// The `--help` text for the binary of `day`
+    pub fn usage(day: u8) -> String {
+        format!(
+            "\
+Usage: day{:02} [options] [-]
+
+Options:
+  -p, --part <1|2>     Only solve the given part
+  -i, --input <path>   Read the input from <path> instead of Input/day{:02}.txt
+  -                    Read the input from stdin (same as --input -)
+  -h, --help           Show this message",
+            day, day
+        )
+    }
+
+    /// The parts selected with `--part`, or both parts if none was given
+    pub fn parts(&self) -> Vec<Part> {
+        selected_parts(self.part)
+    }
+
+    /// Read the input for `day` as selected with `--input` or `-`
+    pub fn read_input(&self, day: u8) -> Result<String, AocError> {
+        read_input(day, self.input.as_deref())
+    }
+}
+
+/// The whole `main` of the `dayXX` binaries: parse [`DayArgs`], solve the
+/// selected parts and print the answers
+///
+/// Answers go to stdout and the timing report to stderr, so the output can
+/// be piped on.
+pub fn run_day_binary(number: u8) -> ExitCode {
+    let day = days::get(number).unwrap_or_else(|| panic!("day {} is not registered", number));
+    let args = match DayArgs::parse(std::env::args().skip(1)) {
+        Ok(args) if args.help => {
+            println!("{}", DayArgs::usage(day.number));
+            return ExitCode::SUCCESS;
+        }
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, DayArgs::usage(day.number));
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match args.read_input(day.number) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let parsed = day.parse(&input);
+    let ctx = Context::default();
+    for part in args.parts() {
+        println!("Part {}: {}", part, day.solve(&parsed, part, &ctx));
+    }
+    eprint!("{}", render_report(&take_spans()));
+    ExitCode::SUCCESS
+}
+
+fn selected_parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+/// Read the input for `day` from `path`, from stdin if `path` is [`STDIN`],
+/// or from `Input/dayXX.txt` (downloading it if needed) if there is no path
+pub fn read_input(day: u8, path: Option<&Path>) -> Result<String, AocError> {
+    match path {
+        Some(path) if path == Path::new(STDIN) => try_read_stdin(),
+        Some(path) => try_read_input_from_path(path),
+        None => try_read_input(day),
+    }
+}
+
+/// The value following an option, or an error naming the option
+pub fn flag_value(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("Missing value for {}", flag))
+}
+
+/// Parse the numeric value following an option
+pub fn flag_number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = flag_value(flag, value)?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value '{}' for {}", value, flag))
+}
+
+/// Parse a day number (1-25)
+pub fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "Invalid day '{}': expected a number from 1 to 25",
+            value
+        )),
+    }
+}
+
+/// Parse a part number (1 or 2)
+pub fn parse_part(value: &str) -> Result<Part, String> {
+    value
+        .parse::<u8>()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("Invalid part '{}': expected 1 or 2", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_day() {
+        let args = RunArgs::parse(["4", "--part", "2"]).unwrap();
+        assert_eq!(args.days, DaySelection::One(4));
+        assert_eq!(args.parts(), vec![Part::Two]);
+        assert_eq!(args.input, None);
+    }
+
+    #[test]
+    fn test_parse_all_and_input() {
+        let args = RunArgs::parse(["--all"]).unwrap();
+        assert_eq!(args.days, DaySelection::All);
+        assert_eq!(args.parts(), vec![Part::One, Part::Two]);
+
+        let args = RunArgs::parse(["1", "--input", "path/to/file"]).unwrap();
+        assert_eq!(args.input, Some(PathBuf::from("path/to/file")));
+    }
+
+    #[test]
+    fn test_parse_trace() {
+        let args = RunArgs::parse(["1", "--trace", "day01=debug", "-t", "info"]).unwrap();
+        assert_eq!(args.trace, TraceFilter::all(Level::Info));
+
+        let args = RunArgs::parse(["--all", "-v", "--trace", "day05=trace"]).unwrap();
+        assert_eq!(args.trace.level_for("day01"), Some(Level::Debug));
+        assert_eq!(args.trace.level_for("day05"), Some(Level::Trace));
+        assert!(RunArgs::parse(["1"]).unwrap().trace.is_off());
+    }
+
+    #[test]
+    fn test_parse_with_extra_option() {
+        let mut warmup = 0;
+        let args = RunArgs::parse_with(["3", "--warmup", "10"], |flag, rest| match flag {
+            "--warmup" => {
+                warmup = flag_number(flag, rest.next())?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        })
+        .unwrap();
+        assert_eq!(args.days, DaySelection::One(3));
+        assert_eq!(warmup, 10);
+    }
+
+    #[test]
+    fn test_parse_day_args() {
+        let args = DayArgs::parse(["--part", "2", "-"]).unwrap();
+        assert_eq!(args.parts(), vec![Part::Two]);
+        assert_eq!(args.input, Some(PathBuf::from(STDIN)));
+
+        let args = DayArgs::parse(["-i", "alt.txt"]).unwrap();
+        assert_eq!(args.parts(), Part::ALL.to_vec());
+        assert_eq!(args.input, Some(PathBuf::from("alt.txt")));
+        assert!(DayArgs::parse(["-h"]).unwrap().help);
+        assert_eq!(DayArgs::parse(Vec::<String>::new()), Ok(DayArgs::default()));
+
+        assert!(DayArgs::parse(["-", "--input", "alt.txt"]).is_err());
+        assert!(DayArgs::parse(["alt.txt"]).is_err());
+        assert!(DayArgs::parse(["--part", "3"]).is_err());
+        assert!(DayArgs::parse(["--all"]).is_err());
+        assert!(DayArgs::usage(4).contains("Input/day04.txt"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(RunArgs::parse(Vec::<String>::new()).is_err());
+        assert!(RunArgs::parse(["4", "--all"]).is_err());
+        assert!(RunArgs::parse(["--all", "--input", "x"]).is_err());
+        assert!(RunArgs::parse(["4", "--part", "3"]).is_err());
+        assert!(RunArgs::parse(["4", "--part"]).is_err());
+        assert!(RunArgs::parse(["26"]).is_err());
+        assert!(RunArgs::parse(["4", "--bogus"]).is_err());
+        assert!(RunArgs::parse(["4", "--trace", "day04=loud"]).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+//! Throttled HTTP client for the Advent of Code site
+
+use std::fs;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+use crate::AocError;
+
+const USER_AGENT: &str = "github.com/ionabio/AOC2025 (aoc2025 runner)";
+
+/// Authenticated client that spaces its requests by `config.min_delay`
+///
+/// The time of the last request is kept in `<input_dir>/.last_request`, so
+/// the delay also holds across separate runs.
+pub struct AocClient<'a> {
+    config: &'a Config,
+    session: &'a str,
+}
+
+impl<'a> AocClient<'a> {
+    /// Create a client, failing if no session token is configured
+    pub fn new(config: &'a Config) -> Result<Self, AocError> {
+        let session = config.session.as_deref().ok_or(AocError::MissingSession)?;
+        Ok(Self { config, session })
+    }
+
+    /// The full URL for a path such as `/2025/day/1/input`
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url, path)
+    }
+
+    /// GET `path` and return the response body
+    pub fn get(&self, path: &str) -> Result<String, AocError> {
+        let url = self.url(path);
+        self.throttle();
+        let response = self.request(ureq::get(&url)).call();
+        read_response(&url, response)
+    }
+
+    /// POST a form to `path` and return the response body
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, AocError> {
+        let url = self.url(path);
+        self.throttle();
+        let response = self.request(ureq::post(&url)).send_form(fields);
+        read_response(&url, response)
+    }
+
+    fn request(&self, request: ureq::Request) -> ureq::Request {
+        request
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+    }
+
+    /// Sleep until `min_delay` has passed since the last request, then record this one
+    fn throttle(&self) {
+        let stamp = self.config.input_dir.join(".last_request");
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|text| text.trim().parse::<u64>().ok())
+            .map(Duration::from_millis);
+        if let Some(last) = last {
+            let wait = (last + self.config.min_delay).saturating_sub(unix_now());
+            if !wait.is_zero() {
+                thread::sleep(wait);
+            }
+        }
+        // Bookkeeping only: failing to record the time must not block the request
+        let _ = fs::create_dir_all(&self.config.input_dir);
+        // Rounded up to whole milliseconds: rounding down would let the next
+        // request go out up to a millisecond before `min_delay` has passed
+        let millis = unix_now().as_nanos().div_ceil(1_000_000);
+        let _ = fs::write(&stamp, millis.to_string());
+    }
+}
+
+fn unix_now() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> Result<String, AocError> {
+    match response {
+        Ok(response) => response.into_string().map_err(|err| AocError::Http {
+            url: url.to_string(),
+            status: None,
+            message: err.to_string(),
+        }),
+        Err(ureq::Error::Status(status, response)) => Err(AocError::Http {
+            url: url.to_string(),
+            status: Some(status),
+            message: response
+                .into_string()
+                .map(|body| body.trim().to_string())
+                .unwrap_or_default(),
+        }),
+        Err(err) => Err(AocError::Http {
+            url: url.to_string(),
+            status: None,
+            message: err.to_string(),
+        }),
+    }
+}
+
+/// A canned local HTTP server standing in for the real site in tests
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Serves `responses` in order, one per connection, and records each request
+    pub struct StubServer {
+        pub base_url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl StubServer {
+        pub fn start(responses: Vec<(u16, &str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&requests);
+            let responses: Vec<(u16, String)> = responses
+                .into_iter()
+                .map(|(s, b)| (s, b.to_string()))
+                .collect();
+
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let Ok((stream, _)) = listener.accept() else {
+                        return;
+                    };
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                            break;
+                        }
+                        if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:")
+                        {
+                            content_length = len.trim().parse().unwrap_or(0);
+                        }
+                        request.push_str(&line);
+                    }
+                    let mut body_bytes = vec![0; content_length];
+                    let _ = reader.read_exact(&mut body_bytes);
+                    request.push_str("\r\n");
+                    request.push_str(&String::from_utf8_lossy(&body_bytes));
+                    recorded.lock().unwrap().push(request);
+
+                    let response = format!(
+                        "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = reader.get_mut().write_all(response.as_bytes());
+                }
+            });
+
+            Self { base_url, requests }
+        }
+
+        /// The raw requests received so far (request line, headers and body)
+        pub fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+}
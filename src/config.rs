@@ -0,0 +1,168 @@
+//! Settings for talking to the Advent of Code site
+//!
+//! Read from `Input/config.toml` and then overridden by environment
+//! variables:
+//!
+//! | key            | environment variable | default                    |
+//! |----------------|----------------------|----------------------------|
+//! | `session`      | `AOC_SESSION`        | none                       |
+//! | `base_url`     | `AOC_BASE_URL`       | `https://adventofcode.com` |
+//! | `year`         | `AOC_YEAR`           | `2025`                     |
+//! | `min_delay_ms` | `AOC_MIN_DELAY_MS`   | `5000`                     |
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::toml_lite::{self, Line, Value};
+use crate::AocError;
+
+/// The default location of the config file
+pub fn config_path() -> PathBuf {
+    PathBuf::from("Input/config.toml")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Value of the `session` cookie from a logged-in browser
+    pub session: Option<String>,
+    /// Site root, without a trailing slash (overridable for testing)
+    pub base_url: String,
+    pub year: u16,
+    /// Minimum time between two requests to the site
+    pub min_delay: Duration,
+    /// Directory holding cached inputs and request bookkeeping
+    pub input_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: "https://adventofcode.com".to_string(),
+            year: 2025,
+            min_delay: Duration::from_millis(5000),
+            input_dir: PathBuf::from("Input"),
+        }
+    }
+}
+
+impl Config {
+    /// Load `Input/config.toml` (if present) and apply environment overrides
+    pub fn load() -> Result<Self, AocError> {
+        let mut config = Self::load_file(config_path())?;
+        config
+            .apply_overrides(|key| std::env::var(key).ok())
+            .map_err(AocError::InvalidConfig)?;
+        Ok(config)
+    }
+
+    /// Load a config file, or the defaults if it doesn't exist
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|(line, message)| AocError::Malformed {
+                path: path.to_path_buf(),
+                line,
+                message,
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(AocError::from_io(path, err)),
+        }
+    }
+
+    /// Parse the config file's text, returning the 1-based line and message on error
+    pub fn parse(text: &str) -> Result<Self, (usize, String)> {
+        let mut config = Self::default();
+        for (line_no, line) in toml_lite::parse(text)? {
+            let (key, value) = match line {
+                Line::Entry(key, value) => (key, value),
+                Line::Table(name) => return Err((line_no, format!("unexpected table [{}]", name))),
+            };
+            let value = match value {
+                Value::String(s) => s,
+                Value::Integer(n) => n.to_string(),
+            };
+            config
+                .set(key, &value)
+                .map_err(|message| (line_no, message))?;
+        }
+        Ok(config)
+    }
+
+    /// Apply `AOC_*` overrides looked up with `get`
+    pub fn apply_overrides(&mut self, get: impl Fn(&str) -> Option<String>) -> Result<(), String> {
+        for (var, key) in [
+            ("AOC_SESSION", "session"),
+            ("AOC_BASE_URL", "base_url"),
+            ("AOC_YEAR", "year"),
+            ("AOC_MIN_DELAY_MS", "min_delay_ms"),
+        ] {
+            if let Some(value) = get(var) {
+                self.set(key, &value)
+                    .map_err(|err| format!("{}: {}", var, err))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "session" => {
+                let session = value.trim();
+                self.session = (!session.is_empty()).then(|| session.to_string());
+            }
+            "base_url" => self.base_url = value.trim_end_matches('/').to_string(),
+            "year" => {
+                self.year = value
+                    .parse()
+                    .map_err(|_| format!("invalid year '{}'", value))?
+            }
+            "min_delay_ms" => {
+                self.min_delay = Duration::from_millis(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid delay '{}'", value))?,
+                )
+            }
+            other => return Err(format!("unknown key '{}'", other)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_overrides() {
+        let mut config = Config::parse(
+            "session = \"abc\"\nbase_url = \"http://localhost:8080/\"\nyear = 2024\n",
+        )
+        .unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.year, 2024);
+        assert_eq!(config.min_delay, Duration::from_millis(5000));
+
+        config
+            .apply_overrides(|var| match var {
+                "AOC_SESSION" => Some("from-env".to_string()),
+                "AOC_MIN_DELAY_MS" => Some("250".to_string()),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(config.session.as_deref(), Some("from-env"));
+        assert_eq!(config.min_delay, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Config::parse("colour = \"red\"").unwrap_err().0, 1);
+        assert_eq!(Config::parse("\n[site]").unwrap_err().0, 2);
+        assert!(Config::default()
+            .apply_overrides(|var| (var == "AOC_YEAR").then(|| "soon".to_string()))
+            .is_err());
+    }
+}
@@ -0,0 +1,273 @@
+//! Day 1: Secret Entrance
+
+use crate::generate::Shape;
+use crate::parse::{each_tagged, tagged, ParseError};
+use crate::rng::Rng;
+use crate::trace::Level;
+use crate::trace_event;
+use crate::{Answer, Context, Solution};
+
+pub struct Day01;
+
+fn parse_input(input: &str) -> Vec<i32> {
+    tagged::<i32>(input, "LR")
+        .unwrap_or_else(|err| panic!("Invalid input: {}", err))
+        .into_iter()
+        .map(|(dir, size)| if dir == 'L' { -size } else { size })
+        .collect()
+}
+
+fn part1(steps: &[i32]) -> i32 {
+    let mut current_position = 50;
+    let mut number_of_zeros = 0;
+    let number_of_dials = 100;
+    //make an enum of direction L, R
+
+    for &step in steps {
+        current_position += step;
+        current_position = current_position.rem_euclid(number_of_dials);
+        if current_position == 0 {
+            number_of_zeros += 1;
+        }
+        trace_event!(
+            Level::Debug,
+            "step",
+            direction = if step < 0 { 'L' } else { 'R' },
+            distance = step.abs(),
+            position = current_position
+        );
+    }
+    trace_event!(Level::Info, "total", zeros = number_of_zeros);
+    number_of_zeros
+}
+
+/// Every malformed step, and an error if there are no steps at all
+fn validate_input(input: &str) -> Vec<ParseError> {
+    let mut errors: Vec<ParseError> = each_tagged::<i32>(input, "LR")
+        .filter_map(Result::err)
+        .collect();
+    if input.trim().is_empty() {
+        errors.push(ParseError::new(1, 1, "no steps found"));
+    }
+    errors
+}
+
+/// A random rotation list, sized by the `steps` and `magnitude` (longest
+/// rotation) shape keys
+fn generate_input(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+    let steps = shape.get_in("steps", 4000, 1, 1_000_000)?;
+    let magnitude = shape.get_in("magnitude", 999, 1, 10_000)?;
+    let mut input = String::new();
+    for _ in 0..steps {
+        let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+        input.push_str(&format!("{}{}\n", direction, rng.int(1..=magnitude)));
+    }
+    Ok(input)
+}
+
+/// Reference for [`part2_fast`]: turns the dial one click at a time
+#[cfg(test)]
+fn part2(steps: &[i32]) -> i32 {
+    let mut current_position = 50;
+    let mut number_of_zeros = 0;
+    let number_of_dials = 100;
+
+    for &step in steps {
+        let mut count = 0;
+        let distance = step.abs();
+        let direction: i32 = if step > 0 { 1 } else { -1 };
+
+        for _ in 0..distance {
+            current_position = (current_position + direction).rem_euclid(number_of_dials);
+            if current_position == 0 {
+                count += 1;
+            }
+        }
+
+        number_of_zeros += count;
+
+        trace_event!(
+            Level::Debug,
+            "step",
+            direction = if step < 0 { 'L' } else { 'R' },
+            distance = step.abs(),
+            position = current_position
+        );
+        if count > 0 {
+            trace_event!(Level::Debug, "hit", times = count);
+        }
+    }
+
+    trace_event!(Level::Info, "total", zeros = number_of_zeros);
+    number_of_zeros
+}
+
+fn part2_fast(steps: &[i32]) -> i32 {
+    let mut current_position = 50;
+    let mut number_of_zeros = 0;
+    let number_of_dials = 100;
+
+    for &step in steps {
+        let distance = step.abs();
+        let count = if step > 0 {
+            // Moving right: count = floor((current + distance) / dials)
+            (current_position + distance) / number_of_dials
+        } else {
+            if current_position == 0 {
+                // Special case: starting at 0, we hit it every full rotation
+                distance / number_of_dials
+            } else if distance < current_position {
+                // We don't reach 0
+                0
+            } else {
+                // We cross 0 at least once, then every full rotation
+                1 + (distance - current_position) / number_of_dials
+            }
+        };
+
+        // Update position
+        current_position = (current_position + step).rem_euclid(number_of_dials);
+        number_of_zeros += count;
+
+        trace_event!(
+            Level::Debug,
+            "step",
+            direction = if step < 0 { 'L' } else { 'R' },
+            distance = step.abs(),
+            position = current_position
+        );
+        if count > 0 {
+            trace_event!(Level::Debug, "hit", times = count);
+        }
+    }
+
+    trace_event!(Level::Info, "total", zeros = number_of_zeros);
+    number_of_zeros
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+    type Parsed = Vec<i32>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(steps: &Self::Parsed, _ctx: &Context) -> Answer {
+        part1(steps).into()
+    }
+
+    fn part2(steps: &Self::Parsed, _ctx: &Context) -> Answer {
+        part2_fast(steps).into()
+    }
+
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate_input(input)
+    }
+
+    fn generate(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+        generate_input(rng, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{DiffConfig, Differential};
+    use crate::fixtures::example;
+    use crate::trace::{capture, TraceFilter, Value};
+    use crate::Part;
+
+    #[test]
+    fn test_part1() {
+        let example = example(1, Part::One);
+        let steps = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part1(&steps))), example.answer);
+    }
+
+    #[test]
+    fn test_part2() {
+        let example = example(1, Part::Two);
+        let steps = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part2(&steps))), example.answer);
+    }
+
+    #[test]
+    fn test_part2_fast() {
+        let example = example(1, Part::Two);
+        let steps = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part2_fast(&steps))), example.answer);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate_input(&example(1, Part::One).input).is_empty());
+        let errors = validate_input("L68\nX30\n\n  R\nR4x");
+        let positions: Vec<(usize, usize)> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(positions, vec![(2, 1), (4, 4), (5, 2)]);
+        assert_eq!(validate_input("\n")[0].message, "no steps found");
+    }
+
+    #[test]
+    fn test_trace_events() {
+        let steps = parse_input(&example(1, Part::Two).input);
+        let filter = TraceFilter::parse("day01=debug").unwrap();
+        let (zeros, events) = capture(filter, || part2_fast(&steps));
+        let moves = events.iter().filter(|event| event.name == "step").count();
+        assert_eq!(moves, steps.len());
+        // Every time the dial passes zero is reported as part of a hit
+        let hits: u64 = events
+            .iter()
+            .filter_map(|event| match event.field("times") {
+                Some(Value::Int(times)) => Some(*times as u64),
+                _ => None,
+            })
+            .sum();
+        assert_eq!(hits, zeros as u64);
+        assert_eq!(
+            events.last().unwrap().field("zeros"),
+            Some(&Value::Int(zeros as i64))
+        );
+    }
+
+    #[test]
+    fn test_part2_differential() {
+        let part2 = Differential {
+            name: "day01 part 2",
+            reference: |steps: &Vec<i32>| part2(steps),
+            optimised: |steps: &Vec<i32>| part2_fast(steps),
+        };
+        assert_eq!(
+            part2.compare(&parse_input(&example(1, Part::Two).input)),
+            None
+        );
+        // Mostly short turns, with some that go round the dial several times
+        part2.check(&DiffConfig::default(), |rng| {
+            (0..rng.index(40))
+                .map(|_| {
+                    let distance = if rng.chance(0.2) {
+                        rng.int(0..=450)
+                    } else {
+                        rng.int(0..=99)
+                    };
+                    let step = if rng.chance(0.5) { -distance } else { distance };
+                    step as i32
+                })
+                .collect()
+        });
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        let shape = Shape::parse("steps=50,magnitude=300").unwrap();
+        for seed in 0..20 {
+            let input = generate_input(&mut Rng::new(seed), &shape).unwrap();
+            assert!(validate_input(&input).is_empty(), "{}", input);
+            let steps = parse_input(&input);
+            assert_eq!(steps.len(), 50);
+            assert!(steps.iter().all(|step| (1..=300).contains(&step.abs())));
+        }
+        assert!(generate_input(&mut Rng::new(0), &Shape::new().with("steps", 0)).is_err());
+    }
+}
@@ -0,0 +1,283 @@
+//! Day 2: Gift Shop
+
+use crate::digits::Digits;
+use crate::generate::Shape;
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{each_record, records_separated, ParseError};
+use crate::rng::Rng;
+use crate::{Answer, Context, Solution};
+use std::collections::HashSet;
+
+pub struct Day02;
+
+/// Parse input into a set of ID ranges (overlapping ranges are merged)
+fn parse_ranges(input: &str) -> IntervalSet {
+    records_separated::<(i64, i64)>(input, ",", "-")
+        .unwrap_or_else(|err| panic!("Invalid input: {}", err))
+        .into_iter()
+        .collect()
+}
+
+/// Every malformed range, including ranges that start after they end
+fn validate_ranges(input: &str) -> Vec<ParseError> {
+    each_record::<(i64, i64)>(input, ",", "-")
+        .filter_map(|record| match record {
+            Err(err) => Some(err),
+            Ok((text, (start, end))) if start > end => Some(ParseError::at(
+                input,
+                text,
+                format!("range {} starts after it ends", text),
+            )),
+            Ok(_) => None,
+        })
+        .collect()
+}
+
+/// Random comma-separated ranges, sized by the `ranges`, `digits` (widest
+/// start ID) and `span` (longest range) shape keys
+fn generate_ranges(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+    let count = shape.get_in("ranges", 35, 1, 100_000)?;
+    let digits = shape.get_in("digits", 10, 1, 17)?;
+    let span = shape.get_in("span", 100_000, 0, 1_000_000_000_000)?;
+    let ranges: Vec<String> = (0..count)
+        .map(|_| {
+            let width = rng.int(1..=digits) as u32;
+            let start = rng.int(10_i64.pow(width - 1)..=10_i64.pow(width) - 1);
+            format!("{}-{}", start, start + rng.int(0..=span))
+        })
+        .collect();
+    Ok(ranges.join(",") + "\n")
+}
+
+/// A range's bounds as unsigned IDs, or `None` if it holds no valid IDs
+fn id_bounds(&Interval { start, end }: &Interval) -> Option<(u64, u64)> {
+    let end = u64::try_from(end).ok()?;
+    Some((start.max(0) as u64, end))
+}
+
+/// Generate all invalid IDs with exactly 2 repetitions in given ranges
+fn generate_invalid_ids_part1(ranges: &IntervalSet) -> Vec<u64> {
+    let mut invalid_ids = HashSet::new();
+    
+    for (start, end) in ranges.iter().filter_map(id_bounds) {
+        let start_digits = start.digit_count();
+        let end_digits = end.digit_count();
+        
+        // For each possible number of digits in range
+        for num_digits in start_digits..=end_digits {
+            // Part 1: exactly 2 repetitions, so pattern length is num_digits/2
+            if num_digits % 2 == 0 {
+                let pattern_len = num_digits / 2;
+                
+                // Range of valid patterns (no leading zeros)
+                let pattern_min = u64::pow10(pattern_len - 1).unwrap();
+                let pattern_max = u64::pow10(pattern_len).unwrap() - 1;
+                
+                for pattern in pattern_min..=pattern_max {
+                    // e.g., pattern=12, result=1212: 12 * (100 + 1) = 12 * 101 = 1212
+                    let Some(id) = pattern.repeat_block(pattern_len, 2) else {
+                        break;
+                    };
+                    if id >= start && id <= end {
+                        invalid_ids.insert(id);
+                    }
+                }
+            }
+        }
+    }
+    
+    invalid_ids.into_iter().collect()
+}
+
+/// Generate all invalid IDs with at least 2 repetitions in given ranges
+fn generate_invalid_ids_part2(ranges: &IntervalSet) -> Vec<u64> {
+    let mut invalid_ids = HashSet::new();
+    
+    for (start, end) in ranges.iter().filter_map(id_bounds) {
+        // num of digits of start and end
+        let start_digits = start.digit_count();
+        let end_digits = end.digit_count();
+        
+        // For each possible number of digits in range
+        for num_digits in start_digits..=end_digits {
+            // For each pattern length that divides num_digits
+            for pattern_len in 1..=num_digits/2 {
+                // pattern length should fit in the whole number of digits
+                if num_digits % pattern_len == 0 {
+                    let reps = num_digits / pattern_len;
+                    
+                    // Range of valid patterns (no leading zeros)
+                    // e.g., for 2-digit: 10-99
+                    let pattern_min = u64::pow10(pattern_len - 1).unwrap();
+                    let pattern_max = u64::pow10(pattern_len).unwrap() - 1;
+                    
+                    for pattern in pattern_min..=pattern_max {
+                        // e.g., for 3 reps of 2-digit: pattern * (10000 + 100 + 1) = pattern * 10101
+                        let Some(id) = pattern.repeat_block(pattern_len, reps) else {
+                            break;
+                        };
+                        if id >= start && id <= end {
+                            invalid_ids.insert(id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    invalid_ids.into_iter().collect()
+}
+
+fn part1(ranges: &IntervalSet) -> i64 {
+    let invalid_ids = generate_invalid_ids_part1(ranges);
+    invalid_ids.iter().sum::<u64>() as i64
+}
+
+fn part2(ranges: &IntervalSet) -> i64 {
+    let invalid_ids = generate_invalid_ids_part2(ranges);
+    invalid_ids.iter().sum::<u64>() as i64
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Gift Shop";
+    type Parsed = IntervalSet;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_ranges(input)
+    }
+
+    fn part1(ranges: &Self::Parsed, _ctx: &Context) -> Answer {
+        part1(ranges).into()
+    }
+
+    fn part2(ranges: &Self::Parsed, _ctx: &Context) -> Answer {
+        part2(ranges).into()
+    }
+
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate_ranges(input)
+    }
+
+    fn generate(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+        generate_ranges(rng, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{DiffConfig, Differential};
+    use crate::fixtures::example;
+    use crate::rng::Rng;
+    use crate::Part;
+
+    /// Ranges written as `(start, length - 1)`, so shrinking keeps them valid
+    type Ranges = Vec<(i64, i64)>;
+
+    fn range_set(ranges: &Ranges) -> IntervalSet {
+        ranges
+            .iter()
+            .map(|&(start, extra)| (start, start + extra))
+            .collect()
+    }
+
+    /// Every ID in the ranges whose digits are `block` repeated `reps` times
+    /// for an allowed `reps`, found by checking each ID one at a time
+    fn brute_force(ranges: &Ranges, allowed_reps: impl Fn(usize) -> bool) -> Vec<u64> {
+        range_set(ranges)
+            .iter()
+            .flat_map(|interval| interval.start..=interval.end)
+            .filter(|id| {
+                let digits = id.to_string();
+                (1..digits.len()).any(|len| {
+                    digits.len() % len == 0
+                        && allowed_reps(digits.len() / len)
+                        && digits
+                            .as_bytes()
+                            .chunks(len)
+                            .all(|block| block == &digits.as_bytes()[..len])
+                })
+            })
+            .map(|id| id as u64)
+            .collect()
+    }
+
+    fn sorted(mut ids: Vec<u64>) -> Vec<u64> {
+        ids.sort_unstable();
+        ids
+    }
+
+    /// A few ranges, often straddling a power of ten
+    fn random_ranges(rng: &mut Rng) -> Ranges {
+        (0..1 + rng.index(4))
+            .map(|_| {
+                let digits = rng.int(1..=7) as u32;
+                let power = 10_i64.pow(digits);
+                let start = rng
+                    .int(power / 10..=power - 1)
+                    .max(power - rng.int(0..=1500));
+                (start, rng.int(0..=2000))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_enumeration_differential() {
+        let config = DiffConfig::default();
+        Differential {
+            name: "day02 part 1 invalid IDs",
+            reference: |ranges: &Ranges| brute_force(ranges, |reps| reps == 2),
+            optimised: |ranges: &Ranges| sorted(generate_invalid_ids_part1(&range_set(ranges))),
+        }
+        .check(&config, random_ranges);
+        Differential {
+            name: "day02 part 2 invalid IDs",
+            reference: |ranges: &Ranges| brute_force(ranges, |reps| reps >= 2),
+            optimised: |ranges: &Ranges| sorted(generate_invalid_ids_part2(&range_set(ranges))),
+        }
+        .check(&config, random_ranges);
+    }
+
+    #[test]
+    fn test_part1() {
+        let example = example(2, Part::One);
+        let ranges = parse_ranges(&example.input);
+        assert_eq!(Some(Answer::from(part1(&ranges))), example.answer);
+    }
+    #[test]
+    fn test_part2() {
+        let example = example(2, Part::Two);
+        let ranges = parse_ranges(&example.input);
+        assert_eq!(Some(Answer::from(part2(&ranges))), example.answer);
+    }
+    #[test]
+    fn test_validate() {
+        assert!(validate_ranges(&example(2, Part::One).input).is_empty());
+        let errors = validate_ranges("11-22,95-9x,\n30-20,1-2-3");
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::new(1, 10, "invalid i64 '9x'"),
+                ParseError::new(2, 1, "range 30-20 starts after it ends"),
+                ParseError::new(2, 7, "expected 2 fields separated by '-', found 3"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        let shape = Shape::parse("ranges=5,digits=6,span=3000").unwrap();
+        for seed in 0..20 {
+            let input = generate_ranges(&mut Rng::new(seed), &shape).unwrap();
+            assert!(validate_ranges(&input).is_empty(), "{}", input);
+            assert_eq!(input.split(',').count(), 5);
+            let ranges: Ranges = parse_ranges(&input)
+                .iter()
+                .map(|interval| (interval.start, interval.end - interval.start))
+                .collect();
+            let expected: u64 = brute_force(&ranges, |reps| reps >= 2).iter().sum();
+            assert_eq!(part2(&parse_ranges(&input)), expected as i64);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Day 3: Lobby
+
+use crate::digits::Digits;
+use crate::generate::Shape;
+use crate::parse::{check_lines, ParseError};
+use crate::rng::Rng;
+use crate::trace::Level;
+use crate::trace_event;
+use crate::{Answer, Context, Solution};
+
+pub struct Day03;
+
+/// How many batteries part 2 turns on in each bank
+const BATTERIES: usize = 12;
+
+fn parse_input(input: &str) -> Vec<Vec<u8>> {
+    let mut grid = Vec::new();
+    for line in input.lines() {
+        let mut row = Vec::new();
+        for c in line.chars() {
+            row.push(c.to_digit(10).unwrap() as u8);
+        }
+        grid.push(row);
+    }
+    grid
+}
+
+/// Every character that isn't a digit, and every bank too short to pick
+/// [`BATTERIES`] batteries from
+fn validate_input(input: &str) -> Vec<ParseError> {
+    check_lines(input, |line| {
+        let mut errors: Vec<ParseError> = line
+            .char_indices()
+            .filter(|(_, c)| !c.is_ascii_digit())
+            .map(|(idx, c)| {
+                ParseError::at(
+                    line,
+                    &line[idx..],
+                    format!("expected a digit, found '{}'", c),
+                )
+            })
+            .collect();
+        let batteries = line.chars().count();
+        if batteries < BATTERIES {
+            errors.push(ParseError::new(
+                1,
+                1,
+                format!(
+                    "bank has {} batteries, need at least {}",
+                    batteries, BATTERIES
+                ),
+            ));
+        }
+        errors
+    })
+}
+
+/// Random banks of batteries rated 1 to 9, sized by the `banks` and
+/// `batteries` (per bank) shape keys
+fn generate_input(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+    let banks = shape.get_in("banks", 200, 1, 1_000_000)?;
+    let batteries = shape.get_in("batteries", 100, BATTERIES, 1_000_000)?;
+    let mut input = String::new();
+    for _ in 0..banks {
+        input.extend((0..batteries).map(|_| char::from(b'0' + rng.int(1..=9) as u8)));
+        input.push('\n');
+    }
+    Ok(input)
+}
+
+fn part1(grid: &[Vec<u8>]) -> i64 {
+    let mut total = 0;
+    for row in grid {
+        // Find max in all positions except the last, taking the first one on
+        // a tie so the most batteries are left to pick the second from
+        // (`max_by_key` would return the last)
+        let (max_pos, &max_val) = row[..row.len()-1]
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, &val)| val)
+            .unwrap();
+        
+        // Find max in all positions after max_pos
+        let &second_max = row[max_pos+1..].iter().max().unwrap();
+        
+        let max_joltage = u64::from_digits([max_val, second_max]).unwrap() as i64;
+        
+        trace_event!(Level::Debug, "bank", joltage = max_joltage);
+        total += max_joltage;
+    }
+    trace_event!(Level::Info, "total", joltage = total);
+    total
+}
+
+fn part2(grid: &[Vec<u8>]) -> i64 {
+    let mut total = 0;
+
+    for row in grid {
+        let n = row.len();
+        let k = BATTERIES;
+        let mut result = Vec::new();
+        let mut last_pos = -1i32; // Position of last picked digit
+        
+        for i in 0..k {
+            // We need k-i-1 more digits after this one
+            let remaining = k - i - 1;
+            let start = (last_pos + 1) as usize;
+            let end = n - remaining;
+            
+            // Find max in range [start..end] (pick first occurrence on tie)
+            let max_val = *row[start..end].iter().max().unwrap();
+            // we need the first occurrence in case of ties
+            let max_pos = row[start..end]
+                .iter()
+                .position(|&v| v == max_val)
+                .unwrap();
+            
+            let actual_pos = start + max_pos;
+            result.push(max_val);
+            last_pos = actual_pos as i32;
+        }
+        
+        // Convert result to number
+        let joltage = u64::from_digits(result).expect("joltage overflows u64") as i64;
+        
+        trace_event!(Level::Debug, "bank", joltage = joltage);
+        total += joltage;
+    }
+    
+    trace_event!(Level::Info, "total", joltage = total);
+    total
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Lobby";
+    type Parsed = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed, _ctx: &Context) -> Answer {
+        part1(grid).into()
+    }
+
+    fn part2(grid: &Self::Parsed, _ctx: &Context) -> Answer {
+        part2(grid).into()
+    }
+
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate_input(input)
+    }
+
+    fn generate(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+        generate_input(rng, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::example;
+    use crate::Part;
+    #[test]
+    fn test_part1() {
+        let example = example(3, Part::One);
+        let grid = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part1(&grid))), example.answer);
+    }
+    #[test]
+    fn test_part2() {
+        let example = example(3, Part::Two);
+        let grid = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part2(&grid))), example.answer);
+    }
+    #[test]
+    fn test_part1_tied_maximum() {
+        // Both 9s are candidates for the first battery; only the first one
+        // leaves the other 9 to pick second
+        assert_eq!(part1(&parse_input("991\n")), 99);
+        assert_eq!(part1(&parse_input("9891\n")), 99);
+    }
+    #[test]
+    fn test_validate() {
+        assert!(validate_input(&example(3, Part::One).input).is_empty());
+        let errors = validate_input("98765432111111x\n81811\n");
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::new(1, 15, "expected a digit, found 'x'"),
+                ParseError::new(2, 1, "bank has 5 batteries, need at least 12"),
+            ]
+        );
+    }
+    #[test]
+    fn test_generated_inputs() {
+        let shape = Shape::parse("banks=10,batteries=15").unwrap();
+        for seed in 0..20 {
+            let input = generate_input(&mut Rng::new(seed), &shape).unwrap();
+            assert!(validate_input(&input).is_empty(), "{}", input);
+            let grid = parse_input(&input);
+            assert!(grid.iter().all(|bank| bank.len() == 15));
+            // The best pair of batteries, trying every pair
+            let expected: i64 = grid
+                .iter()
+                .map(|bank| {
+                    let pairs =
+                        (0..bank.len()).flat_map(|i| (i + 1..bank.len()).map(move |j| (i, j)));
+                    pairs
+                        .map(|(i, j)| i64::from(bank[i] * 10 + bank[j]))
+                        .max()
+                        .unwrap()
+                })
+                .sum();
+            assert_eq!(part1(&grid), expected);
+        }
+        assert!(generate_input(&mut Rng::new(0), &Shape::new().with("batteries", 11)).is_err());
+    }
+}
@@ -0,0 +1,290 @@
+//! Day 4: Printing Department
+
+use std::fmt;
+
+use crate::generate::Shape;
+use crate::grid::Grid;
+use crate::parse::{check_lines, ParseError};
+use crate::rng::Rng;
+use crate::trace::Level;
+use crate::trace_event;
+use crate::{Answer, Context, Solution};
+
+pub struct Day04;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cell {
+    Empty,
+    Roll,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Empty => write!(f, "."),
+            Cell::Roll => write!(f, "@"),
+        }
+    }
+}
+
+fn cell(c: char) -> Option<Cell> {
+    match c {
+        '.' => Some(Cell::Empty),
+        '@' => Some(Cell::Roll),
+        _ => None,
+    }
+}
+
+fn parse_grid(input: &str) -> Grid<Cell> {
+    Grid::parse(input, cell).unwrap_or_else(|err| panic!("Invalid grid: {}", err))
+}
+
+/// Every unknown character and every row whose width differs from the first
+fn validate_grid(input: &str) -> Vec<ParseError> {
+    let input = input.trim_end_matches(['\n', '\r']);
+    let width = input.lines().next().map_or(0, |line| line.chars().count());
+    check_lines(input, |line| {
+        let mut errors: Vec<ParseError> = line
+            .char_indices()
+            .filter(|&(_, c)| cell(c).is_none())
+            .map(|(idx, c)| {
+                ParseError::at(
+                    line,
+                    &line[idx..],
+                    format!("expected '.' or '@', found '{}'", c),
+                )
+            })
+            .collect();
+        let found = line.chars().count();
+        if found != width {
+            errors.push(ParseError::new(
+                1,
+                found.min(width) + 1,
+                format!("row has {} cells, expected {}", found, width),
+            ));
+        }
+        errors
+    })
+}
+
+/// A random map, sized by the `width` and `height` shape keys, where
+/// `density` is the chance of each cell holding a roll
+fn generate_grid(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+    let width = shape.get_in("width", 140, 1, 100_000)?;
+    let height = shape.get_in("height", width, 1, 100_000)?;
+    let density = shape.get_in("density", 0.6, 0.0, 1.0)?;
+    let mut input = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        input.extend((0..width).map(|_| if rng.chance(density) { '@' } else { '.' }));
+        input.push('\n');
+    }
+    Ok(input)
+}
+
+/// Count filled neighbors in all 8 directions (diagonal + adjacent)
+fn count_filled_neighbors(grid: &Grid<Cell>, row: usize, col: usize) -> usize {
+    grid.neighbors8(row, col)
+        .filter(|&pos| grid[pos] == Cell::Roll)
+        .count()
+}
+
+/// Find all accessible rolls (those with < 4 filled neighbors)
+#[cfg(test)]
+fn find_accessible(grid: &Grid<Cell>) -> Vec<(usize, usize)> {
+    grid.iter()
+        .filter(|(row, col, cell)| {
+            **cell == Cell::Roll && count_filled_neighbors(grid, *row, *col) < 4
+        })
+        .map(|(row, col, _)| (row, col))
+        .collect()
+}
+
+fn part1(grid: &Grid<Cell>) -> i32 {
+    // Count rolls that have fewer than 4 filled neighbors
+    // (accessible by forklifts)
+    grid.iter()
+        .filter(|(row, col, cell)| {
+            **cell == Cell::Roll && count_filled_neighbors(grid, *row, *col) < 4
+        })
+        .count() as i32
+}
+
+/// Reference for [`part2_fast`]: rescans the whole grid after every round
+#[cfg(test)]
+fn part2(grid: &Grid<Cell>) -> i32 {
+    let mut grid = grid.clone();
+    let mut total_removed = 0;
+    
+    // Keep removing accessible rolls until none are left
+    loop {
+        let accessible = find_accessible(&grid);
+        
+        if accessible.is_empty() {
+            break;
+        }
+        
+        // Remove all accessible rolls
+        for (row, col) in &accessible {
+            trace_event!(Level::Trace, "remove", row = *row, col = *col);
+            grid[(*row, *col)] = Cell::Empty;
+        }
+        
+        total_removed += accessible.len();
+        trace_event!(
+            Level::Debug,
+            "round",
+            removed = accessible.len(),
+            total = total_removed
+        );
+    }
+    
+    trace_event!(Level::Info, "total", removed = total_removed);
+    total_removed as i32
+}
+
+fn part2_fast(grid: &Grid<Cell>) -> i32 {
+    let mut grid = grid.clone();
+    let mut neighbors = grid.map(|_| 0);
+    let mut queue = Vec::new();
+    for (row, col, cell) in grid.iter() {
+        if *cell == Cell::Roll {
+            neighbors[(row, col)] = count_filled_neighbors(&grid, row, col);
+            if neighbors[(row, col)] < 4 {
+                queue.push((row, col));
+            }
+        }
+    }
+
+    // Removing a roll can only make its neighbours accessible, and each roll
+    // is queued once: either at the start or when its count drops to 3
+    let mut total_removed: i32 = 0;
+    while let Some((row, col)) = queue.pop() {
+        trace_event!(Level::Trace, "remove", row = row, col = col);
+        grid[(row, col)] = Cell::Empty;
+        total_removed += 1;
+        for pos in grid.neighbors8(row, col) {
+            if grid[pos] == Cell::Roll {
+                neighbors[pos] -= 1;
+                if neighbors[pos] == 3 {
+                    queue.push(pos);
+                }
+            }
+        }
+    }
+
+    trace_event!(Level::Info, "total", removed = total_removed);
+    total_removed
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Printing Department";
+    type Parsed = Grid<Cell>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Parsed, _ctx: &Context) -> Answer {
+        part1(grid).into()
+    }
+
+    fn part2(grid: &Self::Parsed, _ctx: &Context) -> Answer {
+        part2_fast(grid).into()
+    }
+
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate_grid(input)
+    }
+
+    fn generate(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+        generate_grid(rng, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{DiffConfig, Differential, Shrink};
+    use crate::fixtures::example;
+    use crate::Part;
+
+    impl Shrink for Cell {
+        fn shrink(&self) -> Vec<Self> {
+            match self {
+                Cell::Roll => vec![Cell::Empty],
+                Cell::Empty => Vec::new(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let example = example(4, Part::One);
+        let grid = parse_grid(&example.input);
+        assert_eq!(Some(Answer::from(part1(&grid))), example.answer);
+    }
+    #[test]
+    fn test_part2() {
+        let example = example(4, Part::Two);
+        let grid = parse_grid(&example.input);
+        assert_eq!(Some(Answer::from(part2(&grid))), example.answer);
+    }
+    #[test]
+    fn test_part2_fast() {
+        let example = example(4, Part::Two);
+        let grid = parse_grid(&example.input);
+        assert_eq!(Some(Answer::from(part2_fast(&grid))), example.answer);
+    }
+    #[test]
+    fn test_removal_differential() {
+        let removal = Differential {
+            name: "day04 part 2",
+            reference: part2,
+            optimised: part2_fast,
+        };
+        removal.check(&DiffConfig::default(), |rng| {
+            let (width, height) = (1 + rng.index(12), 1 + rng.index(12));
+            let density = *rng.pick(&[0.3, 0.6, 0.9]);
+            let cells = (0..width * height)
+                .map(|_| {
+                    if rng.chance(density) {
+                        Cell::Roll
+                    } else {
+                        Cell::Empty
+                    }
+                })
+                .collect();
+            Grid::from_cells(width, height, cells)
+        });
+    }
+    #[test]
+    fn test_validate() {
+        assert!(validate_grid(&example(4, Part::One).input).is_empty());
+        let errors = validate_grid("..@.\n.x@#\n@@\n....\n\n");
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::new(2, 2, "expected '.' or '@', found 'x'"),
+                ParseError::new(2, 4, "expected '.' or '@', found '#'"),
+                ParseError::new(3, 3, "row has 2 cells, expected 4"),
+            ]
+        );
+    }
+    #[test]
+    fn test_generated_inputs() {
+        let shape = Shape::parse("width=30,height=20,density=0.7").unwrap();
+        for seed in 0..20 {
+            let input = generate_grid(&mut Rng::new(seed), &shape).unwrap();
+            assert!(validate_grid(&input).is_empty(), "{}", input);
+            let grid = parse_grid(&input);
+            assert_eq!((grid.width(), grid.height()), (30, 20));
+        }
+        let full = generate_grid(
+            &mut Rng::new(0),
+            &Shape::parse("width=3,density=1").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(full, "@@@\n@@@\n@@@\n");
+    }
+}
@@ -0,0 +1,193 @@
+//! Day 5: Cafeteria
+
+use crate::generate::Shape;
+use crate::interval::IntervalSet;
+use crate::parse::{each_record, records, sections, ParseError};
+use crate::rng::Rng;
+use crate::trace::Level;
+use crate::trace_event;
+use crate::{Answer, Context, Solution};
+
+pub struct Day05;
+
+fn parse_input(input: &str) -> (IntervalSet, Vec<i64>) {
+    let [ranges, ids] = sections(input)[..] else {
+        panic!("Invalid input: expected ranges and IDs separated by a blank line");
+    };
+    let ranges: Vec<(i64, i64)> = ranges
+        .parse(|text| records(text, "-"))
+        .unwrap_or_else(|err| panic!("Invalid input: {}", err));
+    let ids = ids
+        .parse(|text| each_id(text).collect())
+        .unwrap_or_else(|err| panic!("Invalid input: {}", err));
+    // Collecting merges overlapping and touching ranges
+    (ranges.into_iter().collect(), ids)
+}
+
+/// The IDs of a section, one per line
+fn each_id(text: &str) -> impl Iterator<Item = Result<i64, ParseError>> + '_ {
+    text.lines().map(move |line| {
+        let id = line.trim();
+        id.parse()
+            .map_err(|_| ParseError::at(text, id, format!("invalid ID '{}'", id)))
+    })
+}
+
+/// Every malformed or backwards range and every ID that isn't an integer
+fn validate_input(input: &str) -> Vec<ParseError> {
+    let sections = sections(input);
+    let [ranges, ids] = sections[..] else {
+        let line = sections.get(2).map_or(1, |section| section.first_line);
+        return vec![ParseError::new(
+            line,
+            1,
+            format!(
+                "expected ranges and IDs separated by a blank line, found {} section(s)",
+                sections.len()
+            ),
+        )];
+    };
+    let mut errors = ranges.check(|text| {
+        each_record::<(i64, i64)>(text, "\n", "-")
+            .filter_map(|record| match record {
+                Err(err) => Some(err),
+                Ok((range, (start, end))) if start > end => Some(ParseError::at(
+                    text,
+                    range,
+                    format!("range {} starts after it ends", range),
+                )),
+                Ok(_) => None,
+            })
+            .collect::<Vec<_>>()
+    });
+    errors.extend(ids.check(|text| each_id(text).filter_map(Result::err).collect::<Vec<_>>()));
+    errors
+}
+
+/// Random fresh ranges and IDs, sized by the `ranges` and `ids` shape keys,
+/// with IDs up to `max` and ranges up to `span` IDs long
+fn generate_input(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+    let ranges = shape.get_in("ranges", 180, 1, 1_000_000)?;
+    let ids = shape.get_in("ids", 1000, 1, 1_000_000)?;
+    let max = shape.get_in("max", 500_000_000_000_000, 1, 1_000_000_000_000_000_000)?;
+    let span = shape.get_in("span", max / 1000, 0, max)?;
+    let mut input = String::new();
+    for _ in 0..ranges {
+        let start = rng.int(1..=max);
+        input.push_str(&format!("{}-{}\n", start, start + rng.int(0..=span)));
+    }
+    input.push('\n');
+    for _ in 0..ids {
+        input.push_str(&format!("{}\n", rng.int(1..=max)));
+    }
+    Ok(input)
+}
+
+fn part1(ranges: &IntervalSet, numbers: &[i64]) -> i64 {
+    let mut total = 0;
+    for &number in numbers {
+        if let Some(range) = ranges.interval_containing(number) {
+            total += 1;
+            trace_event!(
+                Level::Debug,
+                "hit",
+                id = number,
+                start = range.start,
+                end = range.end
+            );
+        }
+    }
+    total
+}
+
+fn part2(ranges: &IntervalSet) -> i64 {
+    ranges.covered_len() as i64
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Cafeteria";
+    type Parsed = (IntervalSet, Vec<i64>);
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1((ranges, numbers): &Self::Parsed, _ctx: &Context) -> Answer {
+        part1(ranges, numbers).into()
+    }
+
+    fn part2((ranges, _numbers): &Self::Parsed, _ctx: &Context) -> Answer {
+        part2(ranges).into()
+    }
+
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate_input(input)
+    }
+
+    fn generate(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+        generate_input(rng, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::example;
+    use crate::Part;
+    #[test]
+    fn test_part1() {
+        let example = example(5, Part::One);
+        let (ranges, numbers) = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part1(&ranges, &numbers))), example.answer);
+    }
+    #[test]
+    fn test_part2() {
+        let example = example(5, Part::Two);
+        let (ranges, _numbers) = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part2(&ranges))), example.answer);
+    }
+    #[test]
+    fn test_validate() {
+        assert!(validate_input(&example(5, Part::One).input).is_empty());
+        let errors = validate_input("3-5\n10-4x\n20-16\n\n1\n  x7\n");
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::new(2, 4, "invalid i64 '4x'"),
+                ParseError::new(3, 1, "range 20-16 starts after it ends"),
+                ParseError::new(6, 3, "invalid ID 'x7'"),
+            ]
+        );
+        assert_eq!(validate_input("3-5\n\n1\n\n2")[0].line, 5);
+    }
+    #[test]
+    fn test_corrupt_ids_are_reported() {
+        let errors = validate_input("3-5\n\n12abc\n1-2\n4\n");
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::new(3, 1, "invalid ID '12abc'"),
+                ParseError::new(4, 1, "invalid ID '1-2'"),
+            ]
+        );
+        let parsed = std::panic::catch_unwind(|| parse_input("3-5\n\n4\n1-2\n"));
+        let message = *parsed.unwrap_err().downcast::<String>().unwrap();
+        assert_eq!(message, "Invalid input: line 4, column 1: invalid ID '1-2'");
+    }
+    #[test]
+    fn test_generated_inputs() {
+        let shape = Shape::parse("ranges=8,ids=40,max=200,span=30").unwrap();
+        for seed in 0..20 {
+            let input = generate_input(&mut Rng::new(seed), &shape).unwrap();
+            assert!(validate_input(&input).is_empty(), "{}", input);
+            let (ranges, ids) = parse_input(&input);
+            assert_eq!(ids.len(), 40);
+            // Every ID up to `max` plus the longest range, one at a time
+            let fresh = (1..=230).filter(|&id| ranges.contains(id)).count();
+            assert_eq!(part2(&ranges), fresh as i64);
+            let expected = ids.iter().filter(|&&id| ranges.contains(id)).count();
+            assert_eq!(part1(&ranges, &ids), expected as i64);
+        }
+    }
+}
@@ -0,0 +1,412 @@
+//! Day 6: Trash Compactor
+
+use crate::generate::Shape;
+use crate::parse::{check_lines, ParseError};
+use crate::rng::Rng;
+use crate::text_block::TextBlock;
+use crate::trace::Level;
+use crate::trace_event;
+use crate::{Answer, Context, Solution};
+
+pub struct Day06;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Symbol {
+    Add,
+    Multiply,
+}
+
+impl Symbol {
+    fn as_char(&self) -> char {
+        match self {
+            Symbol::Add => '+',
+            Symbol::Multiply => '*',
+        }
+    }
+}
+
+fn parse_input(input: &str) -> (Vec<Vec<i64>>, Vec<Symbol>) {
+    let mut numbers: Vec<Vec<i64>> = Vec::new();
+    let mut symbols: Vec<Symbol> = Vec::new();
+
+    for line in input.lines() {
+        let mut nums = Vec::new();
+        for token in line.split_whitespace() {
+            // Try to parse as i64, else treat as symbol
+            if let Ok(n) = token.parse::<i64>() {
+                nums.push(n);
+            } else {
+                symbols.push(match token {
+                    "+" => Symbol::Add,
+                    "*" => Symbol::Multiply,
+                    _ => panic!("Invalid symbol: {}", token),
+                });
+            }
+        }
+        if !nums.is_empty() {
+            numbers.push(nums);
+        }
+    }
+
+    (numbers, symbols)
+}
+
+/// The worksheet read both ways: row by row for part 1, and column by column
+/// (right to left) for part 2
+pub struct Worksheet {
+    rows: (Vec<Vec<i64>>, Vec<Symbol>),
+    columns: (Vec<Vec<i64>>, Vec<Symbol>),
+}
+
+fn parse_input_part2(input: &str) -> (Vec<Vec<i64>>, Vec<Symbol>) {
+    //parse the last line to symbols
+    let symbols: Vec<Symbol> = input.lines().last().unwrap().split_whitespace().map(|s| match s {
+        "+" => Symbol::Add,
+        "*" => Symbol::Multiply,
+        _ => panic!("Invalid symbol: {}", s),
+    }).collect();
+
+    //everything above the last line holds the numbers
+    let block = TextBlock::new(input);
+    let sheet = block.view();
+    let numbers = sheet.sub(0..sheet.height() - 1, 0..sheet.width());
+
+    // Problems are separated by blank columns; within a problem each column,
+    // read top to bottom, is one number, and columns are read right to left
+    let all_problems: Vec<Vec<i64>> = numbers
+        .split_columns()
+        .iter()
+        .map(|problem| {
+            problem
+                .columns()
+                .rev()
+                .map(|column| {
+                    column
+                        .filter(u8::is_ascii_digit)
+                        .fold(0, |n, digit| n * 10 + i64::from(digit - b'0'))
+                })
+                .collect()
+        })
+        .collect();
+
+    (all_problems, symbols)
+}
+
+/// Every stray character, every row whose numbers don't match the symbols,
+/// and a symbol count that doesn't match the column groups part 2 reads
+fn validate_input(input: &str) -> Vec<ParseError> {
+    let height = input.lines().count();
+    let Some(symbol_line) = input.lines().last() else {
+        return vec![ParseError::new(1, 1, "empty worksheet")];
+    };
+    let symbols = symbol_line.split_whitespace().count();
+
+    let mut row = 0;
+    let mut errors = check_lines(input, |line| {
+        row += 1;
+        if row == height {
+            return line
+                .split_whitespace()
+                .filter(|token| !matches!(*token, "+" | "*"))
+                .map(|token| {
+                    ParseError::at(
+                        line,
+                        token,
+                        format!("expected '+' or '*', found '{}'", token),
+                    )
+                })
+                .collect();
+        }
+        let mut errors: Vec<ParseError> = line
+            .char_indices()
+            .filter(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+            .map(|(idx, c)| {
+                ParseError::at(
+                    line,
+                    &line[idx..],
+                    format!("expected a digit or space, found '{}'", c),
+                )
+            })
+            .collect();
+        let numbers = line.split_whitespace().count();
+        if numbers != symbols {
+            errors.push(ParseError::new(
+                1,
+                1,
+                format!(
+                    "row has {} numbers but there are {} symbols",
+                    numbers, symbols
+                ),
+            ));
+        }
+        errors
+    });
+
+    let block = TextBlock::new(input);
+    let sheet = block.view();
+    let problems = sheet
+        .sub(0..height - 1, 0..sheet.width())
+        .split_columns()
+        .len();
+    if problems != symbols {
+        errors.push(ParseError::new(
+            height,
+            1,
+            format!("{} symbols for {} column groups", symbols, problems),
+        ));
+    }
+    errors
+}
+
+/// A random worksheet of `problems` side by side, each with `operands`
+/// numbers of up to `digits` digits, aligned left or right at random
+///
+/// Read either way, each problem's answer is below 10^(operands × digits),
+/// so `problems` is capped to keep the grand total within an `i64`.
+fn generate_input(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+    let problems = shape.get_in("problems", 1000, 1, 1_000_000)?;
+    let operands = shape.get_in("operands", 4, 1, 18)?;
+    let digits = shape.get_in("digits", 3, 1, 18)?;
+    let Some(bound) = 10i64.checked_pow((operands * digits) as u32) else {
+        return Err(
+            "operands × digits must be at most 18 for one problem's answer to fit".to_string(),
+        );
+    };
+    let max_problems = i64::MAX / bound;
+    if problems as i64 > max_problems {
+        return Err(format!(
+            "at most {} problems fit in the total with operands × digits = {}",
+            max_problems,
+            operands * digits
+        ));
+    }
+
+    let mut lines = vec![String::new(); operands + 1];
+    for problem in 0..problems {
+        let numbers: Vec<String> = (0..operands)
+            .map(|_| {
+                (0..rng.int(1..=digits as i64))
+                    .map(|_| char::from(b'0' + rng.int(1..=9) as u8))
+                    .collect()
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap();
+        let right_aligned = rng.chance(0.5);
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            if problem > 0 {
+                line.push(' ');
+            }
+            if right_aligned {
+                line.push_str(&format!("{:>1$}", number, width));
+            } else {
+                line.push_str(&format!("{:<1$}", number, width));
+            }
+        }
+        let symbol_line = &mut lines[operands];
+        if problem > 0 {
+            symbol_line.push(' ');
+        }
+        let symbol = if rng.chance(0.5) { '+' } else { '*' };
+        symbol_line.push_str(&format!("{:<1$}", symbol, width));
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn transpose_numbers_part1(numbers: &[Vec<i64>]) -> Vec<Vec<i64>> {
+    let mut transposed = Vec::new();
+    for i in 0..numbers[0].len() {
+        let mut row = Vec::new();
+        for number in numbers.iter() {
+            row.push(number[i]);
+        }
+        transposed.push(row);
+    }
+    transposed
+}
+
+fn part1(numbers: &[Vec<i64>], symbols: &[Symbol]) -> i64 {
+    let transposed = transpose_numbers_part1(numbers);
+    let mut total = 0;
+    for (i, row) in transposed.iter().enumerate() {
+        let symbol = &symbols[i];
+        let value = match symbol {
+            Symbol::Add => row.iter().sum::<i64>(),
+            Symbol::Multiply => row.iter().product::<i64>(),
+        };
+        trace_event!(
+            Level::Debug,
+            "problem",
+            index = i,
+            op = symbol.as_char(),
+            operands = row.len(),
+            value = value
+        );
+        total += value;
+    }
+    trace_event!(Level::Info, "total", value = total);
+    total
+}
+
+fn part2(numbers: &[Vec<i64>], symbols: &[Symbol]) -> i64 {
+    let mut total = 0;
+    for (i, row) in numbers.iter().enumerate() {
+        let symbol = &symbols[i];
+        let value = match symbol {
+            Symbol::Add => row.iter().sum::<i64>(),
+            Symbol::Multiply => row.iter().product::<i64>(),
+        };
+        trace_event!(
+            Level::Debug,
+            "problem",
+            index = i,
+            op = symbol.as_char(),
+            operands = row.len(),
+            value = value
+        );
+        total += value;
+    }
+    trace_event!(Level::Info, "total", value = total);
+    total
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+    type Parsed = Worksheet;
+
+    fn parse(input: &str) -> Self::Parsed {
+        Worksheet {
+            rows: parse_input(input),
+            columns: parse_input_part2(input),
+        }
+    }
+
+    fn part1(worksheet: &Self::Parsed, _ctx: &Context) -> Answer {
+        let (numbers, symbols) = &worksheet.rows;
+        part1(numbers, symbols).into()
+    }
+
+    fn part2(worksheet: &Self::Parsed, _ctx: &Context) -> Answer {
+        let (numbers, symbols) = &worksheet.columns;
+        part2(numbers, symbols).into()
+    }
+
+    fn validate(input: &str) -> Vec<ParseError> {
+        validate_input(input)
+    }
+
+    fn generate(rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+        generate_input(rng, shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::example;
+    use crate::Part;
+    #[test]
+    fn test_part1() {
+        let example = example(6, Part::One);
+        let (numbers, symbols) = parse_input(&example.input);
+        assert_eq!(
+            Some(Answer::from(part1(&numbers, &symbols))),
+            example.answer
+        );
+    }
+    #[test]
+    fn test_part2() {
+        let example = example(6, Part::Two);
+        let (numbers, symbols) = parse_input_part2(&example.input);
+        let result = part2(&numbers, &symbols);
+        println!("Part 2 result: {}", result);
+        assert_eq!(Some(Answer::from(result)), example.answer);
+    }
+    
+    #[test]
+    fn test_parse_part2() {
+        let (problems, symbols) = parse_input_part2(&example(6, Part::Two).input);
+        // Each problem's numbers are read one column at a time, leftmost
+        // problem first
+        assert_eq!(
+            problems,
+            vec![
+                vec![356, 24, 1],
+                vec![8, 248, 369],
+                vec![175, 581, 32],
+                vec![4, 431, 623],
+            ]
+        );
+        assert_eq!(
+            symbols,
+            vec![Symbol::Multiply, Symbol::Add, Symbol::Multiply, Symbol::Add]
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(validate_input(&example(6, Part::One).input).is_empty());
+        let errors = validate_input("12 3x\n4  5 6\n*  -");
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::new(1, 5, "expected a digit or space, found 'x'"),
+                ParseError::new(2, 1, "row has 3 numbers but there are 2 symbols"),
+                ParseError::new(3, 4, "expected '+' or '*', found '-'"),
+            ]
+        );
+        assert_eq!(
+            validate_input("1 2\n+ * *"),
+            vec![
+                ParseError::new(1, 1, "row has 2 numbers but there are 3 symbols"),
+                ParseError::new(2, 1, "3 symbols for 2 column groups"),
+            ]
+        );
+    }
+    fn sorted_digits(numbers: impl Iterator<Item = i64>) -> Vec<u8> {
+        let mut digits: Vec<u8> = numbers.flat_map(|n| n.to_string().into_bytes()).collect();
+        digits.sort_unstable();
+        digits
+    }
+
+    #[test]
+    fn test_generated_inputs() {
+        let shape = Shape::parse("problems=12,operands=3,digits=4").unwrap();
+        for seed in 0..20 {
+            let input = generate_input(&mut Rng::new(seed), &shape).unwrap();
+            assert!(validate_input(&input).is_empty(), "{}", input);
+            let (rows, symbols) = parse_input(&input);
+            let (columns, _) = parse_input_part2(&input);
+            assert_eq!((rows.len(), symbols.len(), columns.len()), (3, 12, 12));
+            // Reading a problem by columns reshuffles its digits but keeps them all
+            for (problem, column_numbers) in columns.iter().enumerate() {
+                let by_rows = sorted_digits(rows.iter().map(|row| row[problem]));
+                assert_eq!(by_rows, sorted_digits(column_numbers.iter().copied()));
+            }
+        }
+        assert!(generate_input(
+            &mut Rng::new(0),
+            &Shape::parse("operands=4,digits=5").unwrap()
+        )
+        .is_err());
+    }
+    #[test]
+    fn test_generated_totals_fit() {
+        // i64::MAX / 10^15 problems is the most allowed at this size
+        let largest = Shape::parse("problems=9223,operands=15,digits=1").unwrap();
+        let input = generate_input(&mut Rng::new(1), &largest).unwrap();
+        let (rows, symbols) = parse_input(&input);
+        let (columns, _) = parse_input_part2(&input);
+        assert!(part1(&rows, &symbols) > 0);
+        assert!(part2(&columns, &symbols) > 0);
+
+        let too_many = Shape::parse("problems=9224,operands=15,digits=1").unwrap();
+        assert_eq!(
+            generate_input(&mut Rng::new(1), &too_many),
+            Err("at most 9223 problems fit in the total with operands × digits = 15".to_string())
+        );
+        let shape = Shape::parse("problems=20000,operands=15,digits=1").unwrap();
+        assert!(generate_input(&mut Rng::new(1), &shape).is_err());
+        assert!(generate_input(&mut Rng::new(0), &Shape::new()).is_ok());
+    }
+}
@@ -0,0 +1,49 @@
+//! Solutions for every day, and the registry used to look them up by number
+
+use crate::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+
+static DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+];
+
+/// All registered days, ordered by day number
+pub fn all() -> &'static [Day] {
+    DAYS
+}
+
+/// Look up a registered day by number
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_sorted_and_unique() {
+        let numbers: Vec<u8> = all().iter().map(|d| d.number).collect();
+        let mut sorted = numbers.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(numbers, sorted);
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get(4).map(|d| d.title), Some("Printing Department"));
+        assert!(get(25).is_none());
+    }
+}
@@ -0,0 +1,359 @@
+//! Differential testing of an optimised implementation against a reference
+//!
+//! A fast solution is usually written after a slow, obviously correct one.
+//! Rather than checking the two agree on the puzzle example only, register
+//! both as a [`Differential`] and [`check`](Differential::check) them on a
+//! few hundred generated inputs. When they disagree, the failing input is
+//! shrunk with [`Shrink`] to a small one that still makes them disagree, so
+//! the report shows something like `[-100]` instead of a 40-step list.
+//!
+//! ```
+//! use aoc2025::differential::{DiffConfig, Differential};
+//!
+//! let sum = Differential {
+//!     name: "sum",
+//!     reference: |values: &Vec<i64>| values.iter().sum::<i64>(),
+//!     optimised: |values: &Vec<i64>| values.iter().fold(0, |a, b| a + b),
+//! };
+//! sum.check(&DiffConfig::default(), |rng| (0..rng.index(10)).map(|_| rng.int(-9..=9)).collect());
+//! ```
+
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::rng::Rng;
+
+/// How many inputs to try and how hard to shrink a failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffConfig {
+    /// Generated inputs per run
+    pub cases: usize,
+    /// Seed of the generator stream, so a failure can be replayed
+    pub seed: u64,
+    /// Upper bound on accepted shrink steps
+    pub max_shrinks: usize,
+}
+
+impl Default for DiffConfig {
+    fn default() -> Self {
+        Self {
+            cases: 256,
+            seed: 2025,
+            max_shrinks: 1000,
+        }
+    }
+}
+
+/// A reference and an optimised implementation of the same function
+#[derive(Debug, Clone, Copy)]
+pub struct Differential<I, O> {
+    /// Shown in failure reports, e.g. `day01 part 2`
+    pub name: &'static str,
+    /// The slow, obviously correct version
+    pub reference: fn(&I) -> O,
+    /// The version the solution actually uses
+    pub optimised: fn(&I) -> O,
+}
+
+/// An input the two implementations disagree on
+#[derive(Debug, Clone)]
+pub struct Divergence<I, O> {
+    pub name: &'static str,
+    /// Index of the generated case that first failed
+    pub case: usize,
+    pub seed: u64,
+    /// The input as generated
+    pub original: I,
+    /// The smallest input found that still fails
+    pub input: I,
+    pub reference: O,
+    pub optimised: O,
+    /// How many shrink steps led from `original` to `input`
+    pub shrinks: usize,
+}
+
+impl<I: fmt::Debug, O: fmt::Debug> fmt::Display for Divergence<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}: implementations disagree on case {} (seed {}), shrunk in {} step(s)",
+            self.name, self.case, self.seed, self.shrinks
+        )?;
+        writeln!(f, "  input:     {:?}", self.input)?;
+        writeln!(f, "  reference: {:?}", self.reference)?;
+        writeln!(f, "  optimised: {:?}", self.optimised)?;
+        write!(f, "  original:  {:?}", self.original)
+    }
+}
+
+impl<I: Shrink, O: PartialEq> Differential<I, O> {
+    /// Both results, or `None` if they agree
+    pub fn compare(&self, input: &I) -> Option<(O, O)> {
+        let reference = (self.reference)(input);
+        let optimised = (self.optimised)(input);
+        (reference != optimised).then_some((reference, optimised))
+    }
+
+    /// Compare the two on `config.cases` inputs from `generate`, shrinking
+    /// the first one they disagree on
+    pub fn run(
+        &self,
+        config: &DiffConfig,
+        mut generate: impl FnMut(&mut Rng) -> I,
+    ) -> Result<(), Divergence<I, O>> {
+        let mut rng = Rng::new(config.seed);
+        for case in 0..config.cases {
+            let original = generate(&mut rng);
+            if let Some(results) = self.compare(&original) {
+                return Err(self.shrink(config, case, original, results));
+            }
+        }
+        Ok(())
+    }
+
+    /// [`run`](Self::run), panicking with the shrunk divergence
+    #[track_caller]
+    pub fn check(&self, config: &DiffConfig, generate: impl FnMut(&mut Rng) -> I)
+    where
+        I: fmt::Debug,
+        O: fmt::Debug,
+    {
+        if let Err(divergence) = self.run(config, generate) {
+            panic!("{}", divergence);
+        }
+    }
+
+    /// Greedily replace the input with its first shrink candidate that
+    /// still fails, until no candidate does
+    fn shrink(
+        &self,
+        config: &DiffConfig,
+        case: usize,
+        original: I,
+        (mut reference, mut optimised): (O, O),
+    ) -> Divergence<I, O> {
+        let mut input = original.clone();
+        let mut shrinks = 0;
+        'outer: while shrinks < config.max_shrinks {
+            for candidate in input.shrink() {
+                if let Some(results) = self.compare(&candidate) {
+                    (reference, optimised) = results;
+                    input = candidate;
+                    shrinks += 1;
+                    continue 'outer;
+                }
+            }
+            break;
+        }
+        Divergence {
+            name: self.name,
+            case,
+            seed: config.seed,
+            original,
+            input,
+            reference,
+            optimised,
+            shrinks,
+        }
+    }
+}
+
+/// Values that can propose smaller versions of themselves
+pub trait Shrink: Clone {
+    /// Strictly smaller candidates, most aggressive first
+    ///
+    /// "Smaller" must be well-founded (shrinking always ends), e.g. fewer
+    /// elements or a value closer to zero.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_int {
+    ($($t:ty),+) => {$(
+        impl Shrink for $t {
+            /// Zero, then halfway to zero, then one step closer
+            fn shrink(&self) -> Vec<Self> {
+                let value = *self;
+                let mut candidates = Vec::new();
+                for candidate in [0, value / 2, value - value.signum()] {
+                    if candidate != value && !candidates.contains(&candidate) {
+                        candidates.push(candidate);
+                    }
+                }
+                candidates
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_shrink_uint {
+    ($($t:ty),+) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let value = *self;
+                let mut candidates = Vec::new();
+                for candidate in [0, value / 2, value.saturating_sub(1)] {
+                    if candidate != value && !candidates.contains(&candidate) {
+                        candidates.push(candidate);
+                    }
+                }
+                candidates
+            }
+        }
+    )+};
+}
+
+impl_shrink_int!(i8, i16, i32, i64, isize);
+impl_shrink_uint!(u8, u16, u32, u64, usize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl<A: Shrink, B: Shrink> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let (a, b) = self;
+        let firsts = a.shrink().into_iter().map(|a| (a, b.clone()));
+        let seconds = b.shrink().into_iter().map(|b| (a.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    /// Drop halves, then single elements, then shrink one element
+    fn shrink(&self) -> Vec<Self> {
+        let len = self.len();
+        let mut candidates = Vec::new();
+        if len > 1 {
+            candidates.push(self[..len / 2].to_vec());
+            candidates.push(self[len / 2..].to_vec());
+        }
+        for idx in 0..len {
+            let mut fewer = self.clone();
+            fewer.remove(idx);
+            candidates.push(fewer);
+        }
+        for (idx, item) in self.iter().enumerate() {
+            for smaller in item.shrink() {
+                let mut changed = self.clone();
+                changed[idx] = smaller;
+                candidates.push(changed);
+            }
+        }
+        candidates
+    }
+}
+
+impl<T: Shrink> Shrink for Grid<T> {
+    /// Drop a border row or column, then shrink one cell
+    fn shrink(&self) -> Vec<Self> {
+        let (width, height) = (self.width(), self.height());
+        let mut candidates = Vec::new();
+        let keep = |rows: std::ops::Range<usize>, cols: std::ops::Range<usize>| {
+            let cells = rows
+                .clone()
+                .flat_map(|row| self.row(row)[cols.clone()].iter().cloned())
+                .collect();
+            Grid::from_cells(cols.len(), rows.len(), cells)
+        };
+        if height > 1 {
+            candidates.push(keep(0..height - 1, 0..width));
+            candidates.push(keep(1..height, 0..width));
+        }
+        if width > 1 {
+            candidates.push(keep(0..height, 0..width - 1));
+            candidates.push(keep(0..height, 1..width));
+        }
+        for (row, col, cell) in self.iter() {
+            for smaller in cell.shrink() {
+                let mut changed = self.clone();
+                changed[(row, col)] = smaller;
+                candidates.push(changed);
+            }
+        }
+        candidates
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agreeing_implementations_pass() {
+        let max = Differential {
+            name: "max",
+            reference: |values: &Vec<i32>| values.iter().copied().max().unwrap_or(0),
+            optimised: |values: &Vec<i32>| values.iter().copied().fold(0, i32::max),
+        };
+        let config = DiffConfig::default();
+        let result = max.run(&config, |rng| {
+            (0..rng.index(20))
+                .map(|_| rng.int(0..=100) as i32)
+                .collect()
+        });
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_divergence_is_shrunk() {
+        let max = Differential {
+            name: "max",
+            reference: |values: &Vec<i32>| values.iter().copied().max().unwrap_or(0),
+            // Wrong once the list holds a value above 10
+            optimised: |values: &Vec<i32>| {
+                values
+                    .iter()
+                    .copied()
+                    .filter(|&v| v <= 10)
+                    .max()
+                    .unwrap_or(0)
+            },
+        };
+        let divergence = max
+            .run(&DiffConfig::default(), |rng| {
+                (0..20).map(|_| rng.int(0..=100) as i32).collect()
+            })
+            .unwrap_err();
+        assert_eq!(divergence.case, 0);
+        assert_eq!(divergence.original.len(), 20);
+        // The smallest failing input is a single value just over the limit
+        assert_eq!(divergence.input, vec![11]);
+        assert_eq!((divergence.reference, divergence.optimised), (11, 0));
+        assert!(divergence.to_string().contains("input:     [11]"));
+    }
+
+    #[test]
+    fn test_shrink_candidates() {
+        assert_eq!(10_i32.shrink(), vec![0, 5, 9]);
+        assert_eq!((-3_i64).shrink(), vec![0, -1, -2]);
+        assert_eq!(1_u8.shrink(), vec![0]);
+        assert!(0_u64.shrink().is_empty());
+        assert_eq!(
+            vec![1_u8, 2].shrink(),
+            vec![
+                vec![1],
+                vec![2],
+                vec![2],
+                vec![1],
+                vec![0, 2],
+                vec![1, 0],
+                vec![1, 1]
+            ]
+        );
+
+        let grid = Grid::from_cells(2, 2, vec![true, false, false, true]);
+        let shapes: Vec<(usize, usize)> = grid
+            .shrink()
+            .iter()
+            .map(|grid| (grid.width(), grid.height()))
+            .collect();
+        assert_eq!(shapes, vec![(2, 1), (2, 1), (1, 2), (1, 2), (2, 2), (2, 2)]);
+        assert_eq!(grid.shrink()[3].row(1), &[true]);
+    }
+}
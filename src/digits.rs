@@ -0,0 +1,206 @@
+//! Exact decimal digit arithmetic on unsigned integers
+//!
+//! Counting digits with `(n as f64).log10()` goes wrong near powers of ten
+//! once `n` no longer fits in an `f64` mantissa, and gives nonsense for zero.
+//! [`Digits`] does the same jobs with integer arithmetic only: counting
+//! digits, iterating over them from either end, building numbers back from
+//! digits, and constructing numbers made of a repeated block of digits such
+//! as `121212`. Everything that can overflow is checked.
+//!
+//! ```
+//! use aoc2025::digits::Digits;
+//!
+//! assert_eq!(9_999_999_999_999_999_999_u64.digit_count(), 19);
+//! assert_eq!(1234_u64.digits().rev().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+//! assert_eq!(12_u64.repeat_block(2, 3), Some(121212));
+//! ```
+
+/// Decimal digit operations, implemented for `u32`, `u64` and `u128`
+pub trait Digits: Sized + Copy {
+    /// How many decimal digits the number has; zero has one
+    fn digit_count(self) -> u32;
+
+    /// The digits from most to least significant (`.rev()` for the other way)
+    fn digits(self) -> DigitIter<Self>;
+
+    /// The number written by `digits`, most significant first
+    ///
+    /// Returns `None` if a digit is above 9 or the number doesn't fit.
+    /// No digits at all make zero.
+    fn from_digits(digits: impl IntoIterator<Item = u8>) -> Option<Self>;
+
+    /// `10^exp`, or `None` if it doesn't fit
+    fn pow10(exp: u32) -> Option<Self>;
+
+    /// The number written as `len` ones, like `1111`
+    fn repunit(len: u32) -> Option<Self>;
+
+    /// The multiplier that repeats a `block_len`-digit block `reps` times,
+    /// `1 + 10^block_len + 10^(2 * block_len) + ...`
+    ///
+    /// For example `repeat_multiplier(2, 3)` is `10101`, and `12 * 10101`
+    /// is `121212`.
+    fn repeat_multiplier(block_len: u32, reps: u32) -> Option<Self>;
+
+    /// This number written `reps` times in a row, each copy padded to
+    /// `block_len` digits
+    ///
+    /// Returns `None` if the result doesn't fit or the number has more than
+    /// `block_len` digits.
+    fn repeat_block(self, block_len: u32, reps: u32) -> Option<Self>;
+}
+
+/// Iterator over the decimal digits of a number, see [`Digits::digits`]
+#[derive(Debug, Clone)]
+pub struct DigitIter<T> {
+    /// The digits not yet produced
+    value: T,
+    /// How many digits `value` stands for, counting leading zeros
+    len: u32,
+}
+
+macro_rules! impl_digits {
+    ($($t:ty),+) => {$(
+        impl Digits for $t {
+            fn digit_count(self) -> u32 {
+                self.checked_ilog10().map_or(1, |log| log + 1)
+            }
+
+            fn digits(self) -> DigitIter<Self> {
+                DigitIter {
+                    value: self,
+                    len: self.digit_count(),
+                }
+            }
+
+            fn from_digits(digits: impl IntoIterator<Item = u8>) -> Option<Self> {
+                digits.into_iter().try_fold(0 as $t, |n, digit| {
+                    if digit > 9 {
+                        return None;
+                    }
+                    n.checked_mul(10)?.checked_add(<$t>::from(digit))
+                })
+            }
+
+            fn pow10(exp: u32) -> Option<Self> {
+                (10 as $t).checked_pow(exp)
+            }
+
+            fn repunit(len: u32) -> Option<Self> {
+                Self::repeat_multiplier(1, len)
+            }
+
+            fn repeat_multiplier(block_len: u32, reps: u32) -> Option<Self> {
+                let shift = Self::pow10(block_len)?;
+                (0..reps).try_fold(0 as $t, |n, _| n.checked_mul(shift)?.checked_add(1))
+            }
+
+            fn repeat_block(self, block_len: u32, reps: u32) -> Option<Self> {
+                if self != 0 && self.digit_count() > block_len {
+                    return None;
+                }
+                self.checked_mul(Self::repeat_multiplier(block_len, reps)?)
+            }
+        }
+
+        impl Iterator for DigitIter<$t> {
+            type Item = u8;
+
+            fn next(&mut self) -> Option<u8> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.len -= 1;
+                // `len` never exceeds the digit count, so this can't overflow
+                let place = <$t>::pow10(self.len)?;
+                let digit = self.value / place;
+                self.value %= place;
+                Some(digit as u8)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len as usize, Some(self.len as usize))
+            }
+        }
+
+        impl DoubleEndedIterator for DigitIter<$t> {
+            fn next_back(&mut self) -> Option<u8> {
+                if self.len == 0 {
+                    return None;
+                }
+                self.len -= 1;
+                let digit = self.value % 10;
+                self.value /= 10;
+                Some(digit as u8)
+            }
+        }
+
+        impl ExactSizeIterator for DigitIter<$t> {}
+    )+};
+}
+
+impl_digits!(u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_count() {
+        assert_eq!(0_u64.digit_count(), 1);
+        assert_eq!(9_u64.digit_count(), 1);
+        assert_eq!(10_u64.digit_count(), 2);
+        // Where f64 rounding used to tip over into the next power of ten
+        assert_eq!(999_999_999_999_999_999_u64.digit_count(), 18);
+        assert_eq!(1_000_000_000_000_000_000_u64.digit_count(), 19);
+        assert_eq!(u64::MAX.digit_count(), 20);
+        assert_eq!(u128::MAX.digit_count(), 39);
+        assert_eq!(u32::MAX.digit_count(), 10);
+    }
+
+    #[test]
+    fn test_digits_both_ways() {
+        assert_eq!(1203_u64.digits().collect::<Vec<_>>(), vec![1, 2, 0, 3]);
+        assert_eq!(
+            1203_u64.digits().rev().collect::<Vec<_>>(),
+            vec![3, 0, 2, 1]
+        );
+        assert_eq!(0_u32.digits().collect::<Vec<_>>(), vec![0]);
+
+        // Taking from both ends meets in the middle
+        let mut digits = 12345_u128.digits();
+        assert_eq!(digits.len(), 5);
+        assert_eq!((digits.next(), digits.next_back()), (Some(1), Some(5)));
+        assert_eq!(digits.collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        let max: Vec<u8> = u64::MAX.digits().collect();
+        assert_eq!(u64::from_digits(max), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_from_digits() {
+        assert_eq!(u64::from_digits([9, 8, 7]), Some(987));
+        assert_eq!(u64::from_digits([0, 0, 4]), Some(4));
+        assert_eq!(u64::from_digits([]), Some(0));
+        assert_eq!(u64::from_digits([1, 10]), None);
+        assert_eq!(u32::from_digits([4, 2, 9, 4, 9, 6, 7, 2, 9, 6]), None);
+    }
+
+    #[test]
+    fn test_powers_and_repeats() {
+        assert_eq!(u64::pow10(0), Some(1));
+        assert_eq!(u64::pow10(19), Some(10_000_000_000_000_000_000));
+        assert_eq!(u64::pow10(20), None);
+        assert_eq!(u128::pow10(38), Some(10_u128.pow(38)));
+
+        assert_eq!(u64::repunit(4), Some(1111));
+        assert_eq!(u64::repunit(0), Some(0));
+        assert_eq!(u64::repunit(21), None);
+
+        assert_eq!(u64::repeat_multiplier(3, 2), Some(1001));
+        assert_eq!(123_u64.repeat_block(3, 2), Some(123123));
+        assert_eq!(7_u64.repeat_block(2, 3), Some(70707));
+        assert_eq!(123_u64.repeat_block(2, 2), None);
+        assert_eq!(9_999_999_999_u64.repeat_block(10, 2), None);
+    }
+}
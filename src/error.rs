@@ -0,0 +1,132 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Errors produced while loading puzzle input and other local data files
+#[derive(Debug)]
+pub enum AocError {
+    /// The input file does not exist
+    NotFound { path: PathBuf },
+    /// The input file exists but cannot be read
+    PermissionDenied { path: PathBuf },
+    /// The input file is not valid UTF-8
+    InvalidUtf8 { path: PathBuf },
+    /// The input file contains only whitespace
+    EmptyInput { path: PathBuf },
+    /// A data file (such as the answer store) has a syntax error
+    Malformed {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+    /// Any other I/O failure
+    Io { path: PathBuf, source: io::Error },
+    /// A file that would be generated already exists
+    AlreadyExists { path: PathBuf },
+    /// A setting from the config file or environment is invalid
+    InvalidConfig(String),
+    /// Talking to the site requires a session token, but none is configured
+    MissingSession,
+    /// An answer was not submitted because it is already known to be wrong
+    SubmissionRefused(String),
+    /// A request to the site failed or returned an error status
+    Http {
+        url: String,
+        status: Option<u16>,
+        message: String,
+    },
+}
+
+impl AocError {
+    /// Classify an I/O error raised while reading `path`
+    pub fn from_io(path: impl Into<PathBuf>, source: io::Error) -> Self {
+        let path = path.into();
+        match source.kind() {
+            io::ErrorKind::NotFound => AocError::NotFound { path },
+            io::ErrorKind::PermissionDenied => AocError::PermissionDenied { path },
+            io::ErrorKind::InvalidData => AocError::InvalidUtf8 { path },
+            _ => AocError::Io { path, source },
+        }
+    }
+
+    /// The file the error refers to, if any
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            AocError::NotFound { path }
+            | AocError::PermissionDenied { path }
+            | AocError::InvalidUtf8 { path }
+            | AocError::EmptyInput { path }
+            | AocError::Malformed { path, .. }
+            | AocError::Io { path, .. }
+            | AocError::AlreadyExists { path } => Some(path),
+            AocError::InvalidConfig(_)
+            | AocError::MissingSession
+            | AocError::SubmissionRefused(_)
+            | AocError::Http { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::NotFound { path } => write!(f, "Input file not found: {}", path.display()),
+            AocError::PermissionDenied { path } => {
+                write!(
+                    f,
+                    "Permission denied reading input file: {}",
+                    path.display()
+                )
+            }
+            AocError::InvalidUtf8 { path } => {
+                write!(f, "Input file is not valid UTF-8: {}", path.display())
+            }
+            AocError::EmptyInput { path } => write!(f, "Input file is empty: {}", path.display()),
+            AocError::Malformed {
+                path,
+                line,
+                message,
+            } => write!(f, "{}:{}: {}", path.display(), line, message),
+            AocError::Io { path, source } => {
+                write!(
+                    f,
+                    "Failed to read input file {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+            AocError::AlreadyExists { path } => {
+                write!(f, "Refusing to overwrite existing file: {}", path.display())
+            }
+            AocError::InvalidConfig(message) => write!(f, "Invalid configuration: {}", message),
+            AocError::MissingSession => write!(
+                f,
+                "No session token configured (set AOC_SESSION or `session` in Input/config.toml)"
+            ),
+            AocError::SubmissionRefused(reason) => write!(f, "Not submitting: {}", reason),
+            AocError::Http {
+                url,
+                status: Some(status),
+                message,
+            } => write!(
+                f,
+                "Request to {} failed with status {}: {}",
+                url, status, message
+            ),
+            AocError::Http {
+                url,
+                status: None,
+                message,
+            } => write!(f, "Request to {} failed: {}", url, message),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
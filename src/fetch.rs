@@ -0,0 +1,111 @@
+//! Downloading puzzle inputs into the local `Input/` cache
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::client::AocClient;
+use crate::config::Config;
+use crate::{try_read_input_from_path, AocError};
+
+/// Where the cached input for `day` lives
+pub fn cached_input_path(config: &Config, day: u8) -> PathBuf {
+    config.input_dir.join(format!("day{:02}.txt", day))
+}
+
+/// Return the day's input, downloading it first if it isn't cached yet
+///
+/// Once cached, the input is never requested again.
+pub fn fetch_input(day: u8, config: &Config) -> Result<String, AocError> {
+    let path = cached_input_path(config, day);
+    if path.exists() {
+        return try_read_input_from_path(&path);
+    }
+
+    let client = AocClient::new(config)?;
+    let input = client.get(&format!("/{}/day/{}/input", config.year, day))?;
+    fs::create_dir_all(&config.input_dir)
+        .map_err(|err| AocError::from_io(&config.input_dir, err))?;
+    fs::write(&path, &input).map_err(|err| AocError::from_io(&path, err))?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub::StubServer;
+    use std::time::{Duration, Instant};
+
+    fn test_config(name: &str, base_url: &str) -> Config {
+        let input_dir =
+            std::env::temp_dir().join(format!("aoc2025-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&input_dir);
+        Config {
+            session: Some("secret".to_string()),
+            base_url: base_url.to_string(),
+            year: 2025,
+            min_delay: Duration::ZERO,
+            input_dir,
+        }
+    }
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let server = StubServer::start(vec![(200, "L68\nR48\n")]);
+        let config = test_config("fetch-cache", &server.base_url);
+
+        assert_eq!(fetch_input(1, &config).unwrap(), "L68\nR48\n");
+        assert_eq!(fetch_input(1, &config).unwrap(), "L68\nR48\n");
+        assert_eq!(
+            fs::read_to_string(cached_input_path(&config, 1)).unwrap(),
+            "L68\nR48\n"
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/1/input "));
+        assert!(requests[0].contains("session=secret"));
+        fs::remove_dir_all(&config.input_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let server =
+            StubServer::start(vec![(404, "Please don't repeatedly request this endpoint")]);
+        let config = test_config("fetch-errors", &server.base_url);
+        match fetch_input(3, &config) {
+            Err(AocError::Http {
+                status, message, ..
+            }) => {
+                assert_eq!(status, Some(404));
+                assert!(message.starts_with("Please don't"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(!cached_input_path(&config, 3).exists());
+
+        let no_session = Config {
+            session: None,
+            ..config.clone()
+        };
+        assert!(matches!(
+            fetch_input(3, &no_session),
+            Err(AocError::MissingSession)
+        ));
+        let _ = fs::remove_dir_all(&config.input_dir);
+    }
+
+    #[test]
+    fn test_fetch_honours_min_delay() {
+        let server = StubServer::start(vec![(200, "1"), (200, "2")]);
+        let config = Config {
+            min_delay: Duration::from_millis(300),
+            ..test_config("fetch-delay", &server.base_url)
+        };
+
+        let start = Instant::now();
+        fetch_input(1, &config).unwrap();
+        fetch_input(2, &config).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        fs::remove_dir_all(&config.input_dir).unwrap();
+    }
+}
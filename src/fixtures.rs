@@ -0,0 +1,444 @@
+//! Example inputs and expected answers taken from the puzzle descriptions
+//!
+//! Each day's examples live in `fixtures/dayXX.toml`, with one table per part:
+//!
+//! ```toml
+//! [part1]
+//! input = '''
+//! L68
+//! L30
+//! '''
+//! answer = 3
+//! ```
+//!
+//! The files are committed so the example tests run on a fresh checkout.
+//! [`extract_examples`] fills them in from a saved copy of the puzzle page.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::toml_lite::{self, Line, Value};
+use crate::{Answer, AocError, Part};
+
+/// The fixtures file for `day`, relative to the crate root
+pub fn fixtures_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("fixtures/day{:02}.toml", day))
+}
+
+/// Where a saved copy of the puzzle page for `day` is looked for by default
+pub fn puzzle_page_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("Input/day{:02}.html", day))
+}
+
+/// One example from a puzzle description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    /// The answer the puzzle gives for this input, if one was found
+    pub answer: Option<Answer>,
+}
+
+/// The examples for each part of one day
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Fixtures {
+    examples: BTreeMap<Part, Example>,
+}
+
+impl Fixtures {
+    /// Load a fixtures file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|err| AocError::from_io(path, err))?;
+        Self::parse(&text).map_err(|(line, message)| AocError::Malformed {
+            path: path.to_path_buf(),
+            line,
+            message,
+        })
+    }
+
+    /// Like [`save`](Self::save), but refuse to replace an existing file,
+    /// whose answers may have been fixed by hand
+    pub fn save_new(&self, path: impl AsRef<Path>) -> Result<(), AocError> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Err(AocError::AlreadyExists {
+                path: path.to_path_buf(),
+            });
+        }
+        self.save(path)
+    }
+
+    /// Write the fixtures file, creating its directory if needed
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), AocError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|err| AocError::from_io(dir, err))?;
+        }
+        fs::write(path, self.to_toml()).map_err(|err| AocError::from_io(path, err))
+    }
+
+    /// Parse the text of a fixtures file, returning the 1-based line and message on error
+    pub fn parse(text: &str) -> Result<Self, (usize, String)> {
+        let mut fixtures = Self::default();
+        let mut current: Option<(usize, Part, Option<String>, Option<Answer>)> = None;
+        for (line_no, line) in toml_lite::parse(text)? {
+            match line {
+                Line::Table(name) => {
+                    fixtures.finish(current.take())?;
+                    let part = name
+                        .strip_prefix("part")
+                        .and_then(|n| n.parse().ok())
+                        .and_then(Part::from_number)
+                        .ok_or_else(|| {
+                            (
+                                line_no,
+                                format!("expected [part1] or [part2], found [{}]", name),
+                            )
+                        })?;
+                    if fixtures.examples.contains_key(&part) {
+                        return Err((line_no, format!("duplicate table [{}]", name)));
+                    }
+                    current = Some((line_no, part, None, None));
+                }
+                Line::Entry(key, value) => {
+                    let Some((_, _, input, answer)) = current.as_mut() else {
+                        return Err((line_no, format!("entry '{}' outside a [partN] table", key)));
+                    };
+                    match (key, value) {
+                        ("input", Value::String(text)) => *input = Some(text),
+                        ("answer", Value::Integer(n)) => *answer = Some(Answer::Number(n)),
+                        ("answer", Value::String(s)) => *answer = Some(Answer::Text(s)),
+                        ("input", Value::Integer(_)) => {
+                            return Err((line_no, "input must be a string".to_string()))
+                        }
+                        (other, _) => return Err((line_no, format!("unknown key '{}'", other))),
+                    }
+                }
+            }
+        }
+        fixtures.finish(current)?;
+        Ok(fixtures)
+    }
+
+    fn finish(
+        &mut self,
+        table: Option<(usize, Part, Option<String>, Option<Answer>)>,
+    ) -> Result<(), (usize, String)> {
+        if let Some((line_no, part, input, answer)) = table {
+            let input = input.ok_or_else(|| (line_no, format!("[part{}] has no input", part)))?;
+            self.examples.insert(part, Example { input, answer });
+        }
+        Ok(())
+    }
+
+    /// Render the fixtures in the file format read by [`Fixtures::parse`]
+    pub fn to_toml(&self) -> String {
+        let mut text = String::new();
+        for (part, example) in &self.examples {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&format!("[part{}]\n", part));
+            text.push_str(&format!(
+                "input = {}\n",
+                toml_lite::quote_multiline(&example.input)
+            ));
+            match &example.answer {
+                Some(Answer::Number(n)) => text.push_str(&format!("answer = {}\n", n)),
+                Some(Answer::Text(s)) => {
+                    text.push_str(&format!("answer = {}\n", toml_lite::quote(s)))
+                }
+                None => text.push_str("# answer = ?\n"),
+            }
+        }
+        text
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Example> {
+        self.examples.get(&part)
+    }
+
+    pub fn insert(&mut self, part: Part, example: Example) {
+        self.examples.insert(part, example);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.examples.is_empty()
+    }
+}
+
+/// The example for one part of a day, loaded from its fixtures file
+///
+/// Meant for tests, which should fail loudly when a fixture is missing.
+///
+/// # Panics
+/// Panics if the fixtures file cannot be loaded or has no example for `part`
+pub fn example(day: u8, part: Part) -> Example {
+    let path = fixtures_path(day);
+    let fixtures = Fixtures::load(&path).unwrap_or_else(|err| panic!("{}", err));
+    fixtures
+        .get(part)
+        .cloned()
+        .unwrap_or_else(|| panic!("{} has no example for part {}", path.display(), part))
+}
+
+/// Pull the examples out of a saved puzzle page
+///
+/// Each part's description is one `<article>`. Its first `<pre><code>` block
+/// is taken as the example input, and the last emphasised code span
+/// (`<code><em>..</em></code>`, or the other way round) as the expected
+/// answer. Part two usually reuses part one's example, so a part without a
+/// block of its own inherits the previous input. The "Your puzzle answer
+/// was" lines outside the articles are ignored.
+pub fn extract_examples(html: &str) -> Fixtures {
+    let mut fixtures = Fixtures::default();
+    let mut previous_input: Option<String> = None;
+    for (part, article) in Part::ALL.into_iter().zip(elements(html, "article")) {
+        let input = elements(article, "pre")
+            .next()
+            .map(|pre| decode_entities(&strip_tags(pre)))
+            .or_else(|| previous_input.clone());
+        let Some(input) = input else {
+            continue;
+        };
+        let answer = emphasised_code(article).map(|text| {
+            let text = decode_entities(&strip_tags(text));
+            match text.parse::<i64>() {
+                Ok(n) => Answer::Number(n),
+                Err(_) => Answer::Text(text),
+            }
+        });
+        previous_input = Some(input.clone());
+        fixtures.insert(part, Example { input, answer });
+    }
+    fixtures
+}
+
+/// The contents of each `<tag ...>...</tag>` element, in order (not nested)
+fn elements<'a>(html: &'a str, tag: &str) -> impl Iterator<Item = &'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut rest = html;
+    std::iter::from_fn(move || loop {
+        let start = rest.find(&open)?;
+        let after_name = &rest[start + open.len()..];
+        // Don't match a longer tag name that starts the same way
+        if !after_name.starts_with(['>', ' ', '\t', '\n']) {
+            rest = after_name;
+            continue;
+        }
+        let body_start = after_name.find('>')? + 1;
+        let body = &after_name[body_start..];
+        let end = body.find(&close)?;
+        rest = &body[end + close.len()..];
+        return Some(&body[..end]);
+    })
+}
+
+/// The last `<code><em>..</em></code>` or `<em><code>..</code></em>` span
+fn emphasised_code(html: &str) -> Option<&str> {
+    [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .filter_map(|(open, close)| {
+        let start = html.rfind(open)?;
+        let body = &html[start + open.len()..];
+        Some((start, &body[..body.find(close)?]))
+    })
+    .max_by_key(|&(start, _)| start)
+    .map(|(_, text)| text)
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest
+            .find(';')
+            .map(|end| &rest[1..end])
+            .filter(|name| name.len() <= 8);
+        let replacement = entity.and_then(|name| match name {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let code = match name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => name.strip_prefix('#')?.parse().ok(),
+                };
+                code.and_then(char::from_u32)
+            }
+        });
+        match (entity, replacement) {
+            (Some(name), Some(c)) => {
+                decoded.push(c);
+                rest = &rest[name.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2>
+<p>For example:</p>
+<pre><code>L68
+L30
+R48
+</code></pre>
+<p>Intermediate state:</p>
+<pre><code>&lt;<em>50</em>&gt; &amp; more
+</code></pre>
+<p>Because the dial points at <code>0</code> a total of <code><em>3</em></code> times, the password in this example is <code><em>3</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>In the above example, the password would be <em><code>6</code></em>.</p>
+</article>
+<p>Your puzzle answer was <code>5678</code>.</p>
+</main>"#;
+
+    #[test]
+    fn test_extract_examples() {
+        let fixtures = extract_examples(PAGE);
+        assert_eq!(
+            fixtures.get(Part::One),
+            Some(&Example {
+                input: "L68\nL30\nR48\n".to_string(),
+                answer: Some(Answer::Number(3)),
+            })
+        );
+        assert_eq!(
+            fixtures.get(Part::Two),
+            Some(&Example {
+                input: "L68\nL30\nR48\n".to_string(),
+                answer: Some(Answer::Number(6)),
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_part_one_only() {
+        let fixtures = extract_examples(
+            "<article><pre><code>a&lt;b\n</code></pre><p><code><em>abc</em></code></p></article>",
+        );
+        assert_eq!(
+            fixtures.get(Part::One),
+            Some(&Example {
+                input: "a<b\n".to_string(),
+                answer: Some(Answer::Text("abc".to_string())),
+            })
+        );
+        assert_eq!(fixtures.get(Part::Two), None);
+        assert!(extract_examples("<articles></articles>").is_empty());
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("&lt;&gt;&amp;&quot;&#39;&#x41; & &bogus; &"),
+            "<>&\"'A & &bogus; &"
+        );
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let mut fixtures = Fixtures::default();
+        fixtures.insert(
+            Part::One,
+            Example {
+                input: "123 328 \n*   +   ".to_string(),
+                answer: Some(Answer::Number(4277556)),
+            },
+        );
+        fixtures.insert(
+            Part::Two,
+            Example {
+                input: "x\n".to_string(),
+                answer: None,
+            },
+        );
+        let text = fixtures.to_toml();
+        assert_eq!(
+            text,
+            "[part1]\ninput = '''\n123 328 \n*   +   '''\nanswer = 4277556\n\n[part2]\ninput = '''\nx\n'''\n# answer = ?\n"
+        );
+        assert_eq!(Fixtures::parse(&text).unwrap(), fixtures);
+    }
+
+    #[test]
+    fn test_save_new_keeps_existing_file() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-fixtures-{}", std::process::id()));
+        let path = dir.join("fixtures/day07.toml");
+        let mut fixtures = Fixtures::default();
+        fixtures.insert(
+            Part::One,
+            Example {
+                input: "1\n".to_string(),
+                answer: Some(Answer::Number(1)),
+            },
+        );
+        fixtures.save_new(&path).unwrap();
+
+        let mut guessed = Fixtures::default();
+        guessed.insert(
+            Part::One,
+            Example {
+                input: "1\n".to_string(),
+                answer: Some(Answer::Number(2)),
+            },
+        );
+        assert!(matches!(
+            guessed.save_new(&path),
+            Err(AocError::AlreadyExists { .. })
+        ));
+        assert_eq!(Fixtures::load(&path).unwrap(), fixtures);
+        guessed.save(&path).unwrap();
+        assert_eq!(Fixtures::load(&path).unwrap(), guessed);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Fixtures::parse("[part3]\ninput = \"x\"").unwrap_err().0, 1);
+        assert_eq!(Fixtures::parse("answer = 1").unwrap_err().0, 1);
+        assert_eq!(Fixtures::parse("[part1]\nanswer = 1\n").unwrap_err().0, 1);
+        assert_eq!(
+            Fixtures::parse("[part1]\ninput = \"a\"\n[part1]")
+                .unwrap_err()
+                .0,
+            3
+        );
+    }
+}
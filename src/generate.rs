@@ -0,0 +1,157 @@
+//! Random puzzle inputs of a chosen size and shape
+//!
+//! Each day can provide a generator (see [`crate::Solution::generate`]) that
+//! writes a valid input from a seeded [`Rng`](crate::rng::Rng). What "size"
+//! means differs per day, so it is tuned with a [`Shape`]: named knobs such
+//! as `steps=5000,magnitude=99` for day 1 or `width=500,density=0.9` for
+//! day 4. Knobs that aren't given keep the day's defaults, which resemble a
+//! real puzzle input.
+//!
+//! ```
+//! use aoc2025::generate::Shape;
+//! use aoc2025::rng::Rng;
+//!
+//! let day = aoc2025::days::get(1).unwrap();
+//! let shape = Shape::parse("steps=3,magnitude=9").unwrap();
+//! let input = day.generate(&mut Rng::new(1), &shape).unwrap();
+//! assert_eq!(input.lines().count(), 3);
+//! assert!(day.validate(&input).is_empty());
+//! ```
+
+use std::cell::Cell;
+use std::fmt;
+use std::str::FromStr;
+
+/// Named size and shape parameters for a generator, e.g. `ranges=30,digits=8`
+#[derive(Debug, Clone, Default)]
+pub struct Shape {
+    knobs: Vec<Knob>,
+}
+
+#[derive(Debug, Clone)]
+struct Knob {
+    key: String,
+    value: String,
+    /// Whether the generator asked for this knob, to catch misspelt keys
+    used: Cell<bool>,
+}
+
+impl Shape {
+    /// Every knob at the day's default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse comma-separated `key=value` pairs; later pairs override earlier
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut shape = Self::new();
+        for pair in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+        {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| format!("expected key=value, found '{}'", pair))?;
+            shape = shape.with(key.trim(), value.trim());
+        }
+        Ok(shape)
+    }
+
+    /// This shape with `key` set to `value`
+    pub fn with(mut self, key: &str, value: impl ToString) -> Self {
+        self.knobs.retain(|knob| knob.key != key);
+        self.knobs.push(Knob {
+            key: key.to_string(),
+            value: value.to_string(),
+            used: Cell::new(false),
+        });
+        self
+    }
+
+    /// The value of `key`, or `default` if it isn't set
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T, String> {
+        let Some(knob) = self.knobs.iter().find(|knob| knob.key == key) else {
+            return Ok(default);
+        };
+        knob.used.set(true);
+        knob.value
+            .parse()
+            .map_err(|_| format!("invalid value '{}' for {}", knob.value, key))
+    }
+
+    /// Like [`get`](Self::get), but the value must lie in `min..=max`
+    pub fn get_in<T: FromStr + PartialOrd + fmt::Display>(
+        &self,
+        key: &str,
+        default: T,
+        min: T,
+        max: T,
+    ) -> Result<T, String> {
+        let value = self.get(key, default)?;
+        if value < min || value > max {
+            return Err(format!("{} must be between {} and {}", key, min, max));
+        }
+        Ok(value)
+    }
+
+    /// An error naming every knob no [`get`](Self::get) call asked for
+    pub fn check_unused(&self) -> Result<(), String> {
+        let unused: Vec<&str> = self
+            .knobs
+            .iter()
+            .filter(|knob| !knob.used.get())
+            .map(|knob| knob.key.as_str())
+            .collect();
+        if unused.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("unknown shape key(s): {}", unused.join(", ")))
+        }
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, knob) in self.knobs.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}={}", knob.key, knob.value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_get() {
+        let shape = Shape::parse("steps=10, magnitude = 5,steps=20").unwrap();
+        assert_eq!(shape.to_string(), "magnitude=5,steps=20");
+        assert_eq!(shape.get("steps", 1_usize), Ok(20));
+        assert_eq!(shape.get("density", 0.5), Ok(0.5));
+        assert!(shape.check_unused().is_err());
+        assert_eq!(
+            shape.get_in("magnitude", 1, 1, 4),
+            Err("magnitude must be between 1 and 4".to_string())
+        );
+        assert_eq!(shape.check_unused(), Ok(()));
+
+        assert!(Shape::parse("steps").is_err());
+        assert!(Shape::new().with("steps", "x").get("steps", 1_u32).is_err());
+        assert_eq!(Shape::parse("").unwrap().to_string(), "");
+    }
+
+    #[test]
+    fn test_unused_keys_are_reported() {
+        let shape = Shape::parse("stepz=10,width=3").unwrap();
+        shape.get("width", 1_usize).unwrap();
+        assert_eq!(
+            shape.check_unused(),
+            Err("unknown shape key(s): stepz".to_string())
+        );
+    }
+}
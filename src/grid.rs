@@ -0,0 +1,491 @@
+//! A dense two-dimensional grid, as found in most map-shaped puzzles
+//!
+//! Positions are `(row, col)` pairs with `(0, 0)` in the top-left corner.
+
+use std::fmt;
+use std::iter::{Enumerate, StepBy};
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+/// Offsets of the 4 orthogonal neighbours, in reading order
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of all 8 neighbours (including diagonals), in reading order
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Why a char map could not be turned into a grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The mapping function rejected a character (1-based position)
+    InvalidChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    /// A line is shorter or longer than the first one
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::InvalidChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {}, column {}: unexpected character '{}'",
+                line, column, found
+            ),
+            GridError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} columns, found {}",
+                line, expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` × `height` grid with every cell set to `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Build a grid from its cells in row-major order
+    ///
+    /// # Panics
+    /// Panics if `cells` doesn't hold exactly `width * height` values
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parse a char map, one row per line, mapping each character with `cell`
+    ///
+    /// `cell` returns `None` for characters that don't belong in the map.
+    /// Trailing blank lines are ignored.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = 0;
+        let mut height = 0;
+        for (idx, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let row_start = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or(GridError::InvalidChar {
+                    line: idx + 1,
+                    column: col + 1,
+                    found: c,
+                })?);
+            }
+            let row_width = cells.len() - row_start;
+            if idx == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(GridError::RaggedRow {
+                    line: idx + 1,
+                    expected: width,
+                    found: row_width,
+                });
+            }
+            height += 1;
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(row, col)` lies inside the grid
+    pub fn in_bounds(&self, row: usize, col: usize) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.in_bounds(row, col)
+            .then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if self.in_bounds(row, col) {
+            Some(&mut self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// Replace the cell at `(row, col)`, returning the old value if it was in bounds
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Option<T> {
+        self.get_mut(row, col)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Every position and its cell, in reading order: `(row, col, &cell)`
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            cells: self.cells.iter().enumerate(),
+            width: self.width,
+        }
+    }
+
+    /// The first position, in reading order, whose cell matches `predicate`
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, _, cell)| predicate(cell))
+            .map(|(row, col, _)| (row, col))
+    }
+
+    /// The in-bounds orthogonal neighbours of `(row, col)`
+    pub fn neighbors4(&self, row: usize, col: usize) -> Neighbors {
+        Neighbors::new(self, row, col, &ORTHOGONAL)
+    }
+
+    /// The in-bounds neighbours of `(row, col)`, including diagonals
+    pub fn neighbors8(&self, row: usize, col: usize) -> Neighbors {
+        Neighbors::new(self, row, col, &ALL_AROUND)
+    }
+
+    /// The cells of one row
+    ///
+    /// # Panics
+    /// Panics if `row` is out of bounds
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {} out of bounds", row);
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Every row, top to bottom
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        // `chunks_exact` rejects a chunk size of 0, which a 0-wide grid would need
+        self.cells.chunks_exact(self.width.max(1)).take(self.height)
+    }
+
+    /// The cells of one column, top to bottom
+    ///
+    /// # Panics
+    /// Panics if `col` is out of bounds
+    pub fn column(&self, col: usize) -> StepBy<slice::Iter<'_, T>> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    /// Every column, left to right
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = StepBy<slice::Iter<'_, T>>> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Apply `f` to every cell
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Mirror the grid along its main diagonal, so rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |row, col| (col, row))
+    }
+
+    /// Rotate the grid a quarter turn clockwise
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |row, col| {
+            (self.height - 1 - col, row)
+        })
+    }
+
+    /// Rotate the grid a quarter turn counter-clockwise
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |row, col| {
+            (col, self.width - 1 - row)
+        })
+    }
+
+    /// Build a `width` × `height` grid whose cell `(row, col)` is taken from
+    /// `source(row, col)` in this one
+    fn rearranged(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for row in 0..height {
+            for col in 0..width {
+                let (src_row, src_col) = source(row, col);
+                cells.push(self[(src_row, src_col)].clone());
+            }
+        }
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).unwrap_or_else(|| {
+            panic!(
+                "position ({}, {}) out of bounds for a {}x{} grid",
+                row, col, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(row, col).unwrap_or_else(|| {
+            panic!(
+                "position ({}, {}) out of bounds for a {}x{} grid",
+                row, col, width, height
+            )
+        })
+    }
+}
+
+/// Renders the grid back into text, one line per row
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Grid<T> {
+    type Item = (usize, usize, &'a T);
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over `(row, col, &cell)`, returned by [`Grid::iter`]
+pub struct Iter<'a, T> {
+    cells: Enumerate<slice::Iter<'a, T>>,
+    width: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (idx, cell) = self.cells.next()?;
+        Some((idx / self.width, idx % self.width, cell))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
+    }
+}
+
+/// Iterator over the in-bounds neighbours of a position, returned by
+/// [`Grid::neighbors4`] and [`Grid::neighbors8`]
+///
+/// It only holds the grid's size, so the grid can be modified while
+/// iterating.
+#[derive(Debug, Clone)]
+pub struct Neighbors {
+    row: usize,
+    col: usize,
+    width: usize,
+    height: usize,
+    offsets: slice::Iter<'static, (isize, isize)>,
+}
+
+impl Neighbors {
+    fn new<T>(grid: &Grid<T>, row: usize, col: usize, offsets: &'static [(isize, isize)]) -> Self {
+        Self {
+            row,
+            col,
+            width: grid.width,
+            height: grid.height,
+            offsets: offsets.iter(),
+        }
+    }
+}
+
+impl Iterator for Neighbors {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &(dr, dc) in self.offsets.by_ref() {
+            let (Some(row), Some(col)) = (
+                self.row.checked_add_signed(dr),
+                self.col.checked_add_signed(dc),
+            ) else {
+                continue;
+            };
+            if row < self.height && col < self.width {
+                return Some((row, col));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "ab.\n.#c\n";
+
+    fn parse_map(input: &str) -> Result<Grid<char>, GridError> {
+        Grid::parse(input, |c| (c != '?').then_some(c))
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = parse_map(MAP).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(1, 1), Some(&'#'));
+        assert_eq!(grid[(0, 1)], 'b');
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.to_string(), "ab.\n.#c");
+        assert_eq!(parse_map("").unwrap().to_string(), "");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_map("ab\nc?\n"),
+            Err(GridError::InvalidChar {
+                line: 2,
+                column: 2,
+                found: '?'
+            })
+        );
+        assert_eq!(
+            parse_map("abc\nde\n"),
+            Err(GridError::RaggedRow {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_set_and_position() {
+        let mut grid = parse_map(MAP).unwrap();
+        assert_eq!(grid.position(|&c| c == '#'), Some((1, 1)));
+        assert_eq!(grid.set(1, 1, '.'), Some('#'));
+        assert_eq!(grid.set(5, 5, '.'), None);
+        grid[(0, 0)] = 'z';
+        assert_eq!(grid.to_string(), "zb.\n..c");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::filled(3, 3, 0);
+        let around_corner: Vec<_> = grid.neighbors8(0, 0).collect();
+        assert_eq!(around_corner, vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        let orthogonal: Vec<_> = grid.neighbors4(1, 2).collect();
+        assert_eq!(orthogonal, vec![(0, 2), (1, 1), (2, 2)]);
+        assert_eq!(grid.neighbors4(2, 2).count(), 2);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = parse_map(MAP).unwrap();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["ab.", ".#c"]);
+        assert_eq!(grid.row(1), &['.', '#', 'c']);
+        let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+        assert_eq!(columns, vec!["a.", "b#", ".c"]);
+        assert_eq!(grid.column(2).rev().collect::<String>(), "c.");
+        assert_eq!(grid.iter().nth(4), Some((1, 1, &'#')));
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let grid = parse_map(MAP).unwrap();
+        assert_eq!(grid.transpose().to_string(), "a.\nb#\n.c");
+        assert_eq!(grid.rotate_clockwise().to_string(), ".a\n#b\nc.");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), ".c\nb#\na.");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.map(|&c| c == '#').get(1, 1), Some(&true));
+    }
+}
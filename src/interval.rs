@@ -0,0 +1,302 @@
+//! Sets of integers stored as sorted, disjoint, inclusive intervals
+
+use std::fmt;
+use std::slice;
+
+/// An inclusive range of integers, `start..=end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// # Panics
+    /// Panics if `start > end`
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "empty interval {}-{}", start, end);
+        Self { start, end }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// How many integers the interval holds
+    ///
+    /// A `u128`, since the full `i64` range holds 2^64 of them.
+    pub fn size(&self) -> u128 {
+        u128::from(self.end.abs_diff(self.start)) + 1
+    }
+}
+
+/// Written the way puzzles write ranges: `start-end`
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+impl From<(i64, i64)> for Interval {
+    fn from((start, end): (i64, i64)) -> Self {
+        Self::new(start, end)
+    }
+}
+
+/// A set of integers kept as merged intervals
+///
+/// The intervals are sorted, and no two of them overlap or touch, so each
+/// set has exactly one representation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a set from intervals that are already sorted by start
+    fn from_sorted(sorted: impl IntoIterator<Item = Interval>) -> Self {
+        let mut intervals: Vec<Interval> = Vec::new();
+        for interval in sorted {
+            match intervals.last_mut() {
+                // Merge intervals that overlap or touch
+                Some(last) if interval.start <= last.end.saturating_add(1) => {
+                    last.end = last.end.max(interval.end);
+                }
+                _ => intervals.push(interval),
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Add an interval, merging it with any it overlaps or touches
+    pub fn insert(&mut self, interval: impl Into<Interval>) {
+        let interval = interval.into();
+        // The intervals in `first..last` overlap or touch the new one
+        let first = self
+            .intervals
+            .partition_point(|iv| iv.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|iv| iv.start <= interval.end.saturating_add(1));
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Whether `value` is in the set, by binary search
+    pub fn contains(&self, value: i64) -> bool {
+        self.interval_containing(value).is_some()
+    }
+
+    /// The interval of the set that holds `value`, if any
+    pub fn interval_containing(&self, value: i64) -> Option<&Interval> {
+        let idx = self.intervals.partition_point(|iv| iv.end < value);
+        self.intervals.get(idx).filter(|iv| iv.start <= value)
+    }
+
+    /// How many integers the set holds
+    pub fn covered_len(&self) -> u128 {
+        self.intervals.iter().map(Interval::size).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The merged intervals, in increasing order
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Interval> {
+        self.intervals.iter()
+    }
+
+    /// Every integer in either set
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut all: Vec<Interval> = self.iter().chain(other.iter()).copied().collect();
+        all.sort_unstable();
+        Self::from_sorted(all)
+    }
+
+    /// Every integer in both sets
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (a, b) = (&self.intervals, &other.intervals);
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            let start = a[i].start.max(b[j].start);
+            let end = a[i].end.min(b[j].end);
+            if start <= end {
+                intervals.push(Interval { start, end });
+            }
+            // Move past whichever interval ends first; the other may overlap more
+            if a[i].end < b[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Every integer in this set but not in `other`
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        match (self.intervals.first(), self.intervals.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(Interval::new(first.start, last.end)))
+            }
+            _ => Self::new(),
+        }
+    }
+
+    /// Every integer within `bounds` that is not in the set
+    pub fn complement(&self, bounds: Interval) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let mut next = bounds.start;
+        for interval in &self.intervals {
+            if interval.end < next {
+                continue;
+            }
+            if interval.start > bounds.end {
+                break;
+            }
+            if interval.start > next {
+                intervals.push(Interval {
+                    start: next,
+                    end: interval.start - 1,
+                });
+            }
+            match interval.end.checked_add(1) {
+                Some(after) => next = after,
+                // The interval runs to i64::MAX, so nothing is left above it
+                None => return Self { intervals },
+            }
+        }
+        if next <= bounds.end {
+            intervals.push(Interval {
+                start: next,
+                end: bounds.end,
+            });
+        }
+        Self { intervals }
+    }
+}
+
+impl<I: Into<Interval>> FromIterator<I> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = I>>(iter: T) -> Self {
+        let mut intervals: Vec<Interval> = iter.into_iter().map(Into::into).collect();
+        intervals.sort_unstable();
+        Self::from_sorted(intervals)
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Interval;
+    type IntoIter = slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().copied().collect()
+    }
+
+    fn pairs(set: &IntervalSet) -> Vec<(i64, i64)> {
+        set.iter().map(|iv| (iv.start, iv.end)).collect()
+    }
+
+    #[test]
+    fn test_collect_merges() {
+        // The day 5 example ranges
+        let ranges = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(pairs(&ranges), vec![(3, 5), (10, 20)]);
+        assert_eq!(ranges.covered_len(), 14);
+        assert_eq!(pairs(&set(&[(5, 6), (1, 4), (8, 8)])), vec![(1, 6), (8, 8)]);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut ranges = set(&[(1, 2), (6, 7), (10, 12), (20, 25)]);
+        ranges.insert((4, 4));
+        assert_eq!(
+            pairs(&ranges),
+            vec![(1, 2), (4, 4), (6, 7), (10, 12), (20, 25)]
+        );
+        // Touching on both sides
+        ranges.insert((3, 5));
+        assert_eq!(pairs(&ranges), vec![(1, 7), (10, 12), (20, 25)]);
+        // Spanning several intervals
+        ranges.insert((11, 21));
+        assert_eq!(pairs(&ranges), vec![(1, 7), (10, 25)]);
+        ranges.insert((i64::MAX - 1, i64::MAX));
+        ranges.insert((i64::MAX, i64::MAX));
+        assert_eq!(
+            ranges.intervals().last(),
+            Some(&Interval::new(i64::MAX - 1, i64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        let ranges = set(&[(3, 5), (10, 20)]);
+        let members: Vec<i64> = (0..=22).filter(|&n| ranges.contains(n)).collect();
+        assert_eq!(
+            members,
+            [3, 4, 5].into_iter().chain(10..=20).collect::<Vec<_>>()
+        );
+        assert_eq!(ranges.interval_containing(17), Some(&Interval::new(10, 20)));
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn test_size_of_full_range() {
+        let full = Interval::new(i64::MIN, i64::MAX);
+        assert_eq!(full.size(), 1 << 64);
+        assert_eq!(Interval::new(-3, -3).size(), 1);
+        let ranges = set(&[(i64::MIN, -1), (0, i64::MAX)]);
+        assert_eq!(ranges.covered_len(), 1 << 64);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 15)]);
+        let b = set(&[(4, 11), (14, 20)]);
+        assert_eq!(pairs(&a.union(&b)), vec![(1, 20)]);
+        assert_eq!(pairs(&a.intersection(&b)), vec![(4, 5), (10, 11), (14, 15)]);
+        assert_eq!(pairs(&a.difference(&b)), vec![(1, 3), (12, 13)]);
+        assert_eq!(pairs(&b.difference(&a)), vec![(6, 9), (16, 20)]);
+        assert!(a.difference(&a).is_empty());
+        assert_eq!(a.union(&IntervalSet::new()), a);
+    }
+
+    #[test]
+    fn test_complement() {
+        let a = set(&[(1, 5), (10, 15)]);
+        assert_eq!(
+            pairs(&a.complement(Interval::new(0, 20))),
+            vec![(0, 0), (6, 9), (16, 20)]
+        );
+        assert_eq!(pairs(&a.complement(Interval::new(3, 12))), vec![(6, 9)]);
+        assert_eq!(pairs(&a.complement(Interval::new(2, 4))), vec![]);
+        let full = Interval::new(i64::MIN, i64::MAX);
+        assert_eq!(
+            pairs(&IntervalSet::new().complement(full)),
+            vec![(i64::MIN, i64::MAX)]
+        );
+        assert!(set(&[(i64::MIN, i64::MAX)]).complement(full).is_empty());
+    }
+}
@@ -1,44 +1,41 @@
 use std::fs;
-use std::path::Path;
-use std::time::Instant;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 
+pub mod answers;
+pub mod bench;
+pub mod bench_history;
+pub mod cli;
+mod client;
+pub mod config;
+pub mod days;
+pub mod differential;
+pub mod digits;
+mod error;
+pub mod fetch;
+pub mod fixtures;
+pub mod generate;
+pub mod grid;
+pub mod interval;
+mod json;
+pub mod memory;
+pub mod parse;
+pub mod rng;
+pub mod scaffold;
+mod solution;
+pub mod submit;
+pub mod summary;
+pub mod text_block;
+pub mod timing;
+mod toml_lite;
+pub mod trace;
 #[cfg(feature = "viz")]
 pub mod viz;
+pub mod watch;
 
-/// A scoped timer that measures and prints execution time
-///
-/// The timer starts when created and prints the elapsed time when dropped.
-///
-/// # Example
-/// ```
-/// use aoc2025::Timer;
-///
-/// fn some_function() {
-///     let _timer = Timer::new("some_function");
-///     // ... your code here ...
-/// } // Timer automatically prints elapsed time here
-/// ```
-pub struct Timer {
-    name: String,
-    start: Instant,
-}
-
-impl Timer {
-    /// Create a new timer with the given name
-    pub fn new(name: impl Into<String>) -> Self {
-        Self {
-            name: name.into(),
-            start: Instant::now(),
-        }
-    }
-}
-
-impl Drop for Timer {
-    fn drop(&mut self) {
-        let elapsed = self.start.elapsed();
-        println!("⏱️  {} took: {:?}", self.name, elapsed);
-    }
-}
+pub use error::AocError;
+pub use solution::{Answer, Context, Day, ParsedInput, Part, Solution, PARSE_LABEL};
+pub use timing::{with_timers_silenced, Timer};
 
 /// Macro to easily create a scoped timer
 ///
@@ -58,6 +55,11 @@ macro_rules! time_it {
     };
 }
 
+/// The path of the input file for a given day (e.g. `Input/day01.txt`)
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("Input/day{:02}.txt", day))
+}
+
 /// Read the input file for a given day
 ///
 /// # Arguments
@@ -67,11 +69,9 @@ macro_rules! time_it {
 /// The contents of the input file as a String
 ///
 /// # Panics
-/// Panics if the file cannot be read
+/// Panics if the file cannot be read; use [`try_read_input`] to handle the error
 pub fn read_input(day: u8) -> String {
-    let filename = format!("Input/day{:02}.txt", day);
-    fs::read_to_string(&filename)
-        .unwrap_or_else(|_| panic!("Failed to read input file: {}", filename))
+    try_read_input(day).unwrap_or_else(|err| panic!("{}", err))
 }
 
 /// Read the input file from a custom path
@@ -83,11 +83,74 @@ pub fn read_input(day: u8) -> String {
 /// The contents of the input file as a String
 ///
 /// # Panics
-/// Panics if the file cannot be read
+/// Panics if the file cannot be read; use [`try_read_input_from_path`] to handle the error
 pub fn read_input_from_path<P: AsRef<Path>>(path: P) -> String {
+    try_read_input_from_path(path).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Read the input file for a given day, returning an error instead of panicking
+///
+/// If the file is missing and a session token is configured (see
+/// [`config::Config`]), the input is downloaded into `Input/` first. A config
+/// that fails to load means nothing is downloaded.
+///
+/// # Errors
+/// Returns an [`AocError`] naming the file if it is missing, unreadable,
+/// not valid UTF-8, or empty, or if downloading it failed
+pub fn try_read_input(day: u8) -> Result<String, AocError> {
+    read_or_fetch(day, &input_path(day), config::Config::load)
+}
+
+fn read_or_fetch(
+    day: u8,
+    path: &Path,
+    load_config: impl FnOnce() -> Result<config::Config, AocError>,
+) -> Result<String, AocError> {
+    match try_read_input_from_path(path) {
+        // The missing file stays the error to report: a broken config is
+        // not what the caller asked about
+        Err(AocError::NotFound { path }) => match load_config() {
+            Ok(config) if config.session.is_some() => fetch::fetch_input(day, &config),
+            _ => Err(AocError::NotFound { path }),
+        },
+        result => result,
+    }
+}
+
+/// Read the input file from a custom path, returning an error instead of panicking
+///
+/// # Errors
+/// Returns an [`AocError`] naming the file if it is missing, unreadable,
+/// not valid UTF-8, or empty
+pub fn try_read_input_from_path<P: AsRef<Path>>(path: P) -> Result<String, AocError> {
     let path_ref = path.as_ref();
-    fs::read_to_string(path_ref)
-        .unwrap_or_else(|_| panic!("Failed to read input file: {}", path_ref.display()))
+    let contents =
+        fs::read_to_string(path_ref).map_err(|err| AocError::from_io(path_ref, err))?;
+    if contents.trim().is_empty() {
+        return Err(AocError::EmptyInput {
+            path: path_ref.to_path_buf(),
+        });
+    }
+    Ok(contents)
+}
+
+/// Read puzzle input from standard input, returning an error instead of panicking
+///
+/// # Errors
+/// Returns an [`AocError`] for `<stdin>` if it can't be read, is not valid
+/// UTF-8, or is empty
+pub fn try_read_stdin() -> Result<String, AocError> {
+    let path = Path::new("<stdin>");
+    let mut contents = String::new();
+    io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(|err| AocError::from_io(path, err))?;
+    if contents.trim().is_empty() {
+        return Err(AocError::EmptyInput {
+            path: path.to_path_buf(),
+        });
+    }
+    Ok(contents)
 }
 
 /// Parse input into lines, filtering out empty lines
@@ -117,4 +180,42 @@ mod tests {
         let lines = parse_all_lines(input);
         assert_eq!(lines, vec!["line1", "line2", "", "line3"]);
     }
+
+    #[test]
+    fn test_try_read_input_errors() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-read-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("missing.txt");
+        let err = try_read_input_from_path(&missing).unwrap_err();
+        assert!(matches!(err, AocError::NotFound { .. }));
+        assert_eq!(err.path(), Some(missing.as_path()));
+        // A broken config doesn't replace the missing-file error
+        let err = read_or_fetch(1, &missing, || {
+            Err(AocError::InvalidConfig("bad min_delay_ms".to_string()))
+        })
+        .unwrap_err();
+        assert!(matches!(err, AocError::NotFound { .. }));
+        assert_eq!(err.path(), Some(missing.as_path()));
+
+        let empty = dir.join("empty.txt");
+        fs::write(&empty, " \n\n").unwrap();
+        assert!(matches!(
+            try_read_input_from_path(&empty),
+            Err(AocError::EmptyInput { .. })
+        ));
+
+        let binary = dir.join("binary.txt");
+        fs::write(&binary, [0xff, 0xfe, 0x00]).unwrap();
+        assert!(matches!(
+            try_read_input_from_path(&binary),
+            Err(AocError::InvalidUtf8 { .. })
+        ));
+
+        let valid = dir.join("valid.txt");
+        fs::write(&valid, "L68\n").unwrap();
+        assert_eq!(try_read_input_from_path(&valid).unwrap(), "L68\n");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
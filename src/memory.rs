@@ -0,0 +1,279 @@
+//! Opt-in allocation counting for the timing report
+//!
+//! [`CountingAllocator`] wraps the system allocator and keeps per-thread
+//! counts of allocations, bytes allocated and bytes currently live. A binary
+//! opts in by installing it:
+//!
+//! ```
+//! #[global_allocator]
+//! static ALLOCATOR: aoc2025::memory::CountingAllocator = aoc2025::memory::CountingAllocator;
+//!
+//! fn main() {
+//!     let (_, stats) = aoc2025::memory::measure(|| vec![0_u8; 100]);
+//!     assert_eq!(stats.unwrap().bytes, 100);
+//! }
+//! ```
+//!
+//! The `aoc` runner does this when built with `--features count-allocs`.
+//! Every [`Timer`](crate::Timer) then records an [`AllocStats`] for its span,
+//! which the timing report prints next to the elapsed time. Without the
+//! allocator installed nothing is counted and [`measure`] returns `None`.
+//!
+//! Counts are per thread, so memory freed on another thread than the one
+//! that allocated it makes the live figure of both threads drift.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The system allocator, counting what passes through it
+pub struct CountingAllocator;
+
+// SAFETY: every call is forwarded unchanged to `System`; the counting only
+// touches a const-initialised thread-local, which never allocates.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_free(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_free(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Running totals for the current thread
+#[derive(Debug, Clone, Copy, Default)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    live: i64,
+    /// Highest `live` since the innermost open scope began
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+/// Set by the first counted allocation, so an uninstalled allocator costs
+/// the timers nothing
+static COUNTING: AtomicBool = AtomicBool::new(false);
+
+fn record_alloc(size: usize) {
+    if !COUNTING.load(Ordering::Relaxed) {
+        COUNTING.store(true, Ordering::Relaxed);
+    }
+    // `try_with` because allocations still happen while thread-locals are
+    // being torn down
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        c.count += 1;
+        c.bytes += size as u64;
+        c.live += size as i64;
+        c.peak = c.peak.max(c.live);
+        counters.set(c);
+    });
+}
+
+fn record_free(size: usize) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut c = counters.get();
+        c.live -= size as i64;
+        counters.set(c);
+    });
+}
+
+/// Whether a [`CountingAllocator`] is installed (and has seen an allocation)
+pub fn is_counting() -> bool {
+    COUNTING.load(Ordering::Relaxed)
+}
+
+/// What a span allocated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations, counting each `realloc` as one
+    pub count: u64,
+    /// Total bytes requested by those allocations
+    pub bytes: u64,
+    /// Most bytes live at once, on top of what was live when the span began
+    pub peak: u64,
+}
+
+impl AllocStats {
+    /// Combine the stats of two runs of the same span: counts add up, and
+    /// the peak is the higher of the two
+    pub fn merge(&mut self, other: AllocStats) {
+        self.count += other.count;
+        self.bytes += other.bytes;
+        self.peak = self.peak.max(other.peak);
+    }
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// A byte count in binary units, e.g. `512 B` or `1.5 MiB`
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Allocation counting between [`begin`](Self::begin) and
+/// [`finish`](Self::finish) on one thread
+///
+/// Scopes nest: an inner scope's peak is measured from its own start, and
+/// still counts towards the peak of the scope around it.
+#[derive(Debug)]
+pub struct AllocScope {
+    start: Counters,
+}
+
+impl AllocScope {
+    /// Start counting, or `None` if no [`CountingAllocator`] is installed
+    pub fn begin() -> Option<Self> {
+        if !is_counting() {
+            return None;
+        }
+        COUNTERS
+            .try_with(|counters| {
+                let start = counters.get();
+                counters.set(Counters {
+                    peak: start.live,
+                    ..start
+                });
+                AllocScope { start }
+            })
+            .ok()
+    }
+
+    /// What was allocated since [`begin`](Self::begin)
+    pub fn finish(self) -> AllocStats {
+        COUNTERS
+            .try_with(|counters| {
+                let now = counters.get();
+                // Hand the peak back to the enclosing scope
+                counters.set(Counters {
+                    peak: now.peak.max(self.start.peak),
+                    ..now
+                });
+                AllocStats {
+                    count: now.count - self.start.count,
+                    bytes: now.bytes - self.start.bytes,
+                    peak: (now.peak - self.start.live).max(0) as u64,
+                }
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Run `f`, returning what it allocated if counting is enabled
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    let scope = AllocScope::begin();
+    let result = f();
+    (result, scope.map(AllocScope::finish))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    // Installed for the whole library test binary, so every test runs with
+    // counting on
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_counts_allocations() {
+        let (_, stats) = measure(|| {
+            let a = black_box(vec![0_u8; 1000]);
+            let b = black_box(vec![0_u64; 500]);
+            drop(a);
+            drop(b);
+        });
+        let stats = stats.expect("counting allocator is installed");
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.bytes, 5000);
+        assert_eq!(stats.peak, 5000);
+    }
+
+    #[test]
+    fn test_nested_peaks() {
+        let (inner, outer) = measure(|| {
+            let kept = black_box(vec![0_u8; 100]);
+            let (_, inner) = measure(|| drop(black_box(vec![0_u8; 300])));
+            drop(kept);
+            inner
+        });
+        // The inner peak is measured from its own start, the outer one
+        // includes what was already live when the inner scope began
+        assert_eq!(inner.unwrap().peak, 300);
+        assert_eq!(outer.unwrap().peak, 400);
+        assert_eq!(outer.unwrap().count, 2);
+    }
+
+    #[test]
+    fn test_merge_and_format() {
+        let mut stats = AllocStats {
+            count: 2,
+            bytes: 100,
+            peak: 80,
+        };
+        stats.merge(AllocStats {
+            count: 1,
+            bytes: 3 * 1024 * 1024,
+            peak: 40,
+        });
+        assert_eq!(stats.to_string(), "3 allocs, 3.0 MiB, peak 80 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(1023), "1023 B");
+    }
+}
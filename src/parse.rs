@@ -0,0 +1,479 @@
+//! Helpers for the input formats puzzles keep coming back to
+//!
+//! - [`sections`] splits input on blank lines
+//! - [`signed_integers`] / [`unsigned_integers`] pull every number out of free text
+//! - [`records`], [`records_separated`] and [`tagged`] parse delimited
+//!   records such as `3-5`, `11-22,95-115` or `L68` into typed values,
+//!   with errors that point at the offending line and column
+//! - [`each_record`], [`each_tagged`] and [`check_lines`] keep going after
+//!   an error, for validation passes that report every problem at once
+
+use std::any::type_name;
+use std::fmt;
+use std::str::FromStr;
+
+/// Where and why parsing failed (1-based line and column)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error at the start of `part`, which must be a subslice of `text`
+    pub fn at(text: &str, part: &str, message: impl Into<String>) -> Self {
+        let offset = part.as_ptr() as usize - text.as_ptr() as usize;
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Self::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    /// The same error for text that starts `lines` lines further down
+    pub fn shifted(self, lines: usize) -> Self {
+        Self {
+            line: self.line + lines,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A block of lines between blank lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    /// 1-based line number of the section's first line in the whole input
+    pub first_line: usize,
+    /// The section's lines, without the trailing line break
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    /// Run a parser over the section, fixing up error line numbers so they
+    /// refer to the whole input
+    pub fn parse<T>(
+        &self,
+        parser: impl FnOnce(&'a str) -> Result<T, ParseError>,
+    ) -> Result<T, ParseError> {
+        parser(self.text).map_err(|err| err.shifted(self.first_line - 1))
+    }
+
+    /// Run a checker over the section, like [`Section::parse`] but keeping
+    /// every error it reports
+    pub fn check<I>(&self, checker: impl FnOnce(&'a str) -> I) -> Vec<ParseError>
+    where
+        I: IntoIterator<Item = ParseError>,
+    {
+        checker(self.text)
+            .into_iter()
+            .map(|err| err.shifted(self.first_line - 1))
+            .collect()
+    }
+}
+
+/// Split input into its blank-line separated sections
+///
+/// Lines holding only whitespace count as blank, and runs of several blank
+/// lines don't produce empty sections.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    // Byte offset and line number where the current section started
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for (idx, line) in input.split_inclusive('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((section_start, first_line)) = start.take() {
+                sections.push(Section {
+                    first_line,
+                    text: input[section_start..offset].trim_end_matches(['\n', '\r']),
+                });
+            }
+        } else if start.is_none() {
+            start = Some((offset, idx + 1));
+        }
+        offset += line.len();
+    }
+    if let Some((section_start, first_line)) = start {
+        sections.push(Section {
+            first_line,
+            text: input[section_start..].trim_end_matches(['\n', '\r']),
+        });
+    }
+    sections
+}
+
+/// Every integer in `text`, with a `-` directly before the digits read as a sign
+///
+/// A `-` that follows a letter or digit is a separator, so `3-5` gives
+/// `[3, 5]` while `x=-5` gives `[-5]`.
+///
+/// # Panics
+/// Panics if a number doesn't fit in an `i64`
+pub fn signed_integers(text: &str) -> Vec<i64> {
+    digit_runs(text)
+        .map(|(start, end)| {
+            let negative = text[..start].ends_with('-')
+                && !text[..start - 1]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_alphanumeric());
+            let number = if negative {
+                &text[start - 1..end]
+            } else {
+                &text[start..end]
+            };
+            number
+                .parse()
+                .unwrap_or_else(|_| panic!("integer '{}' out of range", number))
+        })
+        .collect()
+}
+
+/// Every run of digits in `text`, ignoring any signs
+///
+/// # Panics
+/// Panics if a number doesn't fit in a `u64`
+pub fn unsigned_integers(text: &str) -> Vec<u64> {
+    digit_runs(text)
+        .map(|(start, end)| {
+            text[start..end]
+                .parse()
+                .unwrap_or_else(|_| panic!("integer '{}' out of range", &text[start..end]))
+        })
+        .collect()
+}
+
+/// Byte ranges of the runs of ASCII digits in `text`
+fn digit_runs(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let bytes = text.as_bytes();
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        let start = pos + bytes[pos..].iter().position(u8::is_ascii_digit)?;
+        let len = bytes[start..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or(bytes.len() - start);
+        pos = start + len;
+        Some((start, pos))
+    })
+}
+
+/// A tuple of fields that [`records`] can parse
+///
+/// Implemented for tuples of 2 to 4 types that implement [`FromStr`].
+pub trait Record: Sized {
+    const FIELDS: usize;
+
+    /// Parse exactly [`Record::FIELDS`] fields, or return the index of the
+    /// first field that failed and why
+    fn from_fields(fields: &[&str]) -> Result<Self, (usize, String)>;
+}
+
+fn parse_field<T: FromStr>(fields: &[&str], idx: usize) -> Result<T, (usize, String)> {
+    fields[idx].parse().map_err(|_| {
+        (
+            idx,
+            format!("invalid {} '{}'", type_name::<T>(), fields[idx]),
+        )
+    })
+}
+
+macro_rules! impl_record {
+    ($count:expr; $($idx:tt: $field:ident),+) => {
+        impl<$($field: FromStr),+> Record for ($($field,)+) {
+            const FIELDS: usize = $count;
+
+            fn from_fields(fields: &[&str]) -> Result<Self, (usize, String)> {
+                Ok(($(parse_field::<$field>(fields, $idx)?,)+))
+            }
+        }
+    };
+}
+
+impl_record!(2; 0: A, 1: B);
+impl_record!(3; 0: A, 1: B, 2: C);
+impl_record!(4; 0: A, 1: B, 2: C, 3: D);
+
+/// Parse every non-blank line of `text` as fields separated by `delimiter`
+///
+/// ```
+/// use aoc2025::parse::records;
+///
+/// let ranges: Vec<(u64, u64)> = records("3-5\n10-14\n", "-").unwrap();
+/// assert_eq!(ranges, vec![(3, 5), (10, 14)]);
+/// ```
+pub fn records<T: Record>(text: &str, delimiter: &str) -> Result<Vec<T>, ParseError> {
+    records_separated(text, "\n", delimiter)
+}
+
+/// Parse records separated by `separator` rather than line breaks, such as
+/// the comma-separated ranges in `11-22,95-115`
+///
+/// Surrounding whitespace is ignored, both around records and fields.
+pub fn records_separated<T: Record>(
+    text: &str,
+    separator: &str,
+    delimiter: &str,
+) -> Result<Vec<T>, ParseError> {
+    each_record(text, separator, delimiter)
+        .map(|record| record.map(|(_, value)| value))
+        .collect()
+}
+
+/// Parse records one at a time, like [`records_separated`], yielding each
+/// record's text along with its value so that callers can keep going after
+/// an error or point further errors at the record
+pub fn each_record<'a, T: Record>(
+    text: &'a str,
+    separator: &'a str,
+    delimiter: &'a str,
+) -> impl Iterator<Item = Result<(&'a str, T), ParseError>> + 'a {
+    text.split(separator)
+        .map(str::trim)
+        .filter(|record| !record.is_empty())
+        .map(move |record| {
+            let fields: Vec<&str> = record.split(delimiter).map(str::trim).collect();
+            if fields.len() != T::FIELDS {
+                return Err(ParseError::at(
+                    text,
+                    record,
+                    format!(
+                        "expected {} fields separated by '{}', found {}",
+                        T::FIELDS,
+                        delimiter,
+                        fields.len()
+                    ),
+                ));
+            }
+            T::from_fields(&fields)
+                .map(|value| (record, value))
+                .map_err(|(idx, message)| ParseError::at(text, fields[idx], message))
+        })
+}
+
+/// Parse every non-blank line of `text` as a one-character tag followed by a
+/// value, like `L68`, accepting only the tags listed in `tags`
+pub fn tagged<T: FromStr>(text: &str, tags: &str) -> Result<Vec<(char, T)>, ParseError> {
+    each_tagged(text, tags).collect()
+}
+
+/// Parse tagged lines one at a time, like [`tagged`], so that callers can
+/// keep going after an error
+pub fn each_tagged<'a, T: FromStr>(
+    text: &'a str,
+    tags: &'a str,
+) -> impl Iterator<Item = Result<(char, T), ParseError>> + 'a {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(move |line| {
+            let mut chars = line.chars();
+            let tag = chars.next().unwrap_or_default();
+            if !tags.contains(tag) {
+                let expected: Vec<String> = tags.chars().map(|t| format!("'{}'", t)).collect();
+                return Err(ParseError::at(
+                    text,
+                    line,
+                    format!("expected one of {}, found '{}'", expected.join(", "), tag),
+                ));
+            }
+            let value = chars.as_str();
+            if value.is_empty() {
+                return Err(ParseError::at(
+                    text,
+                    value,
+                    format!("missing value after '{}'", tag),
+                ));
+            }
+            let parsed = value.parse().map_err(|_| {
+                ParseError::at(
+                    text,
+                    value,
+                    format!("invalid {} '{}'", type_name::<T>(), value),
+                )
+            })?;
+            Ok((tag, parsed))
+        })
+}
+
+/// Run `check` on every line of `text` and gather what it reports
+///
+/// `check` sees one line at a time (without its line break) and reports
+/// errors as if that line were line 1; they come back with their line in
+/// `text`. Return an `Option` for at most one error per line, or a `Vec`.
+pub fn check_lines<I>(text: &str, mut check: impl FnMut(&str) -> I) -> Vec<ParseError>
+where
+    I: IntoIterator<Item = ParseError>,
+{
+    text.lines()
+        .enumerate()
+        .flat_map(|(idx, line)| {
+            check(line)
+                .into_iter()
+                .map(move |err| err.shifted(idx))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let input = "3-5\n10-14\n\n  \n1\n5\r\n\n";
+        assert_eq!(
+            sections(input),
+            vec![
+                Section {
+                    first_line: 1,
+                    text: "3-5\n10-14"
+                },
+                Section {
+                    first_line: 5,
+                    text: "1\n5"
+                },
+            ]
+        );
+        assert!(sections("\n\n").is_empty());
+        assert_eq!(sections("abc")[0].text, "abc");
+    }
+
+    #[test]
+    fn test_section_errors_use_input_lines() {
+        let input = "1-2\n\n3-4\nx-5\n";
+        let second = sections(input)[1];
+        let err = second
+            .parse(|text| records::<(i64, i64)>(text, "-"))
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+
+    #[test]
+    fn test_integers() {
+        let text = "x=-5, y=12..-3; 3-5 a-7 -8";
+        assert_eq!(signed_integers(text), vec![-5, 12, -3, 3, 5, 7, -8]);
+        assert_eq!(unsigned_integers(text), vec![5, 12, 3, 3, 5, 7, 8]);
+        assert!(signed_integers("none").is_empty());
+    }
+
+    #[test]
+    fn test_records() {
+        let ranges: Vec<(i64, i64)> = records("3-5\n\n10-14\n", "-").unwrap();
+        assert_eq!(ranges, vec![(3, 5), (10, 14)]);
+        let ids: Vec<(u64, u64)> = records_separated("11-22, 95-115\n", ",", "-").unwrap();
+        assert_eq!(ids, vec![(11, 22), (95, 115)]);
+        let triples: Vec<(u8, char, String)> = records("1 a bc\n2 d ef", " ").unwrap();
+        assert_eq!(triples[1], (2, 'd', "ef".to_string()));
+    }
+
+    #[test]
+    fn test_record_errors() {
+        assert_eq!(
+            records::<(i64, i64)>("3-5\n10-1x4\n", "-"),
+            Err(ParseError::new(2, 4, "invalid i64 '1x4'"))
+        );
+        assert_eq!(
+            records::<(i64, i64)>("3-5\n  7\n", "-"),
+            Err(ParseError::new(
+                2,
+                3,
+                "expected 2 fields separated by '-', found 1"
+            ))
+        );
+        let err = records_separated::<(u8, u8)>("1-2,3-300", ",", "-").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+        assert_eq!(err.to_string(), "line 1, column 7: invalid u8 '300'");
+    }
+
+    #[test]
+    fn test_every_error() {
+        let text = "1-2, x-3, 4-5-6, 7-8";
+        let errors: Vec<ParseError> = each_record::<(u8, u8)>(text, ",", "-")
+            .filter_map(Result::err)
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::new(1, 6, "invalid u8 'x'"),
+                ParseError::new(1, 11, "expected 2 fields separated by '-', found 3"),
+            ]
+        );
+        let (record, value) = each_record::<(u8, u8)>(text, ",", "-")
+            .last()
+            .unwrap()
+            .unwrap();
+        assert_eq!((record, value), ("7-8", (7, 8)));
+
+        let errors: Vec<ParseError> = each_tagged::<i32>("X1\nL2\nR", "LR")
+            .filter_map(Result::err)
+            .collect();
+        assert_eq!(
+            errors.iter().map(|e| e.line).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn test_check_lines() {
+        let errors = check_lines("ab\n\n  c!\nd!e!", |line| {
+            line.match_indices('!')
+                .map(|(idx, _)| ParseError::at(line, &line[idx..], "unexpected '!'"))
+                .collect::<Vec<_>>()
+        });
+        let positions: Vec<(usize, usize)> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(positions, vec![(3, 4), (4, 2), (4, 4)]);
+
+        let input = "1-2\n\n3\nx\n";
+        let errors = sections(input)[1].check(|text| {
+            check_lines(text, |line| {
+                line.parse::<i64>()
+                    .err()
+                    .map(|_| ParseError::at(line, line, format!("invalid ID '{}'", line)))
+            })
+        });
+        assert_eq!(errors, vec![ParseError::new(4, 1, "invalid ID 'x'")]);
+    }
+
+    #[test]
+    fn test_tagged() {
+        let steps: Vec<(char, i32)> = tagged("L68\nR48\n", "LR").unwrap();
+        assert_eq!(steps, vec![('L', 68), ('R', 48)]);
+        assert_eq!(
+            tagged::<i32>("L68\nX5", "LR"),
+            Err(ParseError::new(2, 1, "expected one of 'L', 'R', found 'X'"))
+        );
+        assert_eq!(
+            tagged::<i32>("L68\nR", "LR"),
+            Err(ParseError::new(2, 2, "missing value after 'R'"))
+        );
+        assert_eq!(
+            tagged::<i32>("L6x8", "LR"),
+            Err(ParseError::new(1, 2, "invalid i32 '6x8'"))
+        );
+    }
+}
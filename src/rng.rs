@@ -0,0 +1,99 @@
+//! A small seeded pseudo-random number generator
+//!
+//! Generated test inputs have to be reproducible from a seed alone, and the
+//! quality bar is "varied enough to find bugs", so a SplitMix64 stream is
+//! plenty and keeps the crate free of a `rand` dependency.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64: every seed, including zero, gives a full-period stream
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform value in `0..bound`; `bound` must not be zero
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Rng::below(0)");
+        // Multiply-shift maps the 64 random bits onto the range with a bias
+        // far too small to matter for test inputs
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+
+    /// A uniform value in `range`, which must not be empty
+    pub fn int(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Rng::int on an empty range");
+        let span = end.abs_diff(start);
+        let offset = if span == u64::MAX {
+            self.next_u64()
+        } else {
+            self.below(span + 1)
+        };
+        start.wrapping_add(offset as i64)
+    }
+
+    /// A uniform index into a collection of `len` items
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// `true` with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        // The top 53 bits as a float in [0, 1)
+        ((self.next_u64() >> 11) as f64) * (1.0 / (1u64 << 53) as f64) < p
+    }
+
+    /// A uniformly chosen element of a non-empty slice
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_stream() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        let first: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        assert_eq!(first, (0..8).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(first, {
+            let mut c = Rng::new(8);
+            (0..8).map(|_| c.next_u64()).collect::<Vec<_>>()
+        });
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            let value = rng.int(-2..=2);
+            assert!((-2..=2).contains(&value));
+            seen[(value + 2) as usize] = true;
+            assert!(rng.below(3) < 3);
+        }
+        assert!(seen.iter().all(|&hit| hit));
+        assert_eq!(rng.int(5..=5), 5);
+        // The full range must not overflow
+        rng.int(i64::MIN..=i64::MAX);
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+}
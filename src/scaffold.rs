@@ -0,0 +1,500 @@
+//! Generating the files for a new day
+//!
+//! A new day needs a solution module registered in `src/days/mod.rs`, a thin
+//! binary in `src/bin/`, a `[[bin]]` entry in `Cargo.toml` and, optionally, a
+//! visualization skeleton. The solution template comes with example tests
+//! that load `fixtures/dayXX.toml` and are ignored until it exists.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::AocError;
+
+/// What to generate for a new day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewDay {
+    pub day: u8,
+    pub title: String,
+    /// Also generate a `dayXX_viz` binary built on `aoc2025::viz`
+    pub viz: bool,
+}
+
+/// The solution module of `day`, relative to the crate root
+pub fn module_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("src/days/day{:02}.rs", day))
+}
+
+impl NewDay {
+    pub fn module_path(&self) -> PathBuf {
+        module_path(self.day)
+    }
+
+    pub fn bin_path(&self) -> PathBuf {
+        PathBuf::from(format!("src/bin/day{:02}.rs", self.day))
+    }
+
+    pub fn viz_path(&self) -> PathBuf {
+        PathBuf::from(format!("src/bin/day{:02}_viz.rs", self.day))
+    }
+
+    /// Source of `src/days/dayXX.rs`
+    pub fn module_source(&self) -> String {
+        self.fill(MODULE_TEMPLATE)
+    }
+
+    /// Source of `src/bin/dayXX.rs`
+    pub fn bin_source(&self) -> String {
+        self.fill(BIN_TEMPLATE)
+    }
+
+    /// Source of `src/bin/dayXX_viz.rs`
+    pub fn viz_source(&self) -> String {
+        self.fill(VIZ_TEMPLATE)
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template
+            .replace("{TITLE_LITERAL}", &format!("{:?}", self.title))
+            .replace("{TITLE}", &self.title)
+            .replace("{NN}", &format!("{:02}", self.day))
+            .replace("{N}", &self.day.to_string())
+    }
+}
+
+/// Create the new day's files under the project `root`
+///
+/// Returns the paths (relative to `root`) that were created or modified.
+/// Nothing is written if any of the new files already exists or the day is
+/// already registered.
+pub fn create_day(root: &Path, new_day: &NewDay) -> Result<Vec<PathBuf>, AocError> {
+    let mut files = vec![
+        (new_day.module_path(), new_day.module_source()),
+        (new_day.bin_path(), new_day.bin_source()),
+    ];
+    if new_day.viz {
+        files.push((new_day.viz_path(), new_day.viz_source()));
+    }
+    for (path, _) in &files {
+        let full_path = root.join(path);
+        if full_path.exists() {
+            return Err(AocError::AlreadyExists { path: full_path });
+        }
+    }
+
+    let registry_path = PathBuf::from("src/days/mod.rs");
+    let registry = read(root, &registry_path)?;
+    let registry = register_day(&registry, new_day.day).map_err(|message| AocError::Malformed {
+        path: root.join(&registry_path),
+        line: 0,
+        message,
+    })?;
+    let manifest_path = PathBuf::from("Cargo.toml");
+    let manifest = add_bin_entries(&read(root, &manifest_path)?, new_day);
+    files.push((registry_path, registry));
+    files.push((manifest_path, manifest));
+
+    for (path, contents) in &files {
+        let full_path = root.join(path);
+        fs::write(&full_path, contents).map_err(|err| AocError::from_io(&full_path, err))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(root: &Path, path: &Path) -> Result<String, AocError> {
+    let full_path = root.join(path);
+    fs::read_to_string(&full_path).map_err(|err| AocError::from_io(&full_path, err))
+}
+
+/// Add `pub mod dayXX;` and the registry entry to the text of `src/days/mod.rs`
+///
+/// Both are inserted in day order next to the existing days.
+pub fn register_day(registry: &str, day: u8) -> Result<String, String> {
+    let module = format!("day{:02}", day);
+    let lines: Vec<&str> = registry.lines().collect();
+
+    let mod_line = format!("pub mod {};", module);
+    let entry_line = format!("    Day::new::<{}::Day{:02}>(),", module, day);
+    if lines.iter().any(|line| line.trim() == mod_line) {
+        return Err(format!("day {} is already registered", day));
+    }
+
+    let mod_idx = insertion_index(&lines, day, |line| {
+        line.strip_prefix("pub mod day")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })
+    .ok_or("no `pub mod dayXX;` declarations found")?;
+    let entry_idx = insertion_index(&lines, day, |line| {
+        line.trim()
+            .strip_prefix("Day::new::<day")?
+            .get(..2)?
+            .parse()
+            .ok()
+    })
+    .ok_or("no `Day::new::<...>()` registry entries found")?;
+
+    let mut lines: Vec<String> = lines.into_iter().map(str::to_string).collect();
+    // Insert the later line first so the earlier index stays valid
+    for (idx, line) in [(mod_idx, mod_line), (entry_idx, entry_line)]
+        .into_iter()
+        .rev()
+    {
+        lines.insert(idx, line);
+    }
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+/// Where a line for `day` goes among the lines for which `day_of` returns a day
+fn insertion_index(lines: &[&str], day: u8, day_of: impl Fn(&str) -> Option<u8>) -> Option<usize> {
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx, day_of(line)?)))
+        .collect();
+    let (first, _) = *days.first()?;
+    Some(
+        days.iter()
+            .rev()
+            .find(|&&(_, existing)| existing < day)
+            .map_or(first, |&(idx, _)| idx + 1),
+    )
+}
+
+/// Append `[[bin]]` entries for the new day to the text of `Cargo.toml`
+///
+/// Entries that already exist are left alone.
+pub fn add_bin_entries(manifest: &str, new_day: &NewDay) -> String {
+    let mut manifest = manifest.trim_end().to_string();
+    let name = format!("day{:02}", new_day.day);
+    if !has_bin(&manifest, &name) {
+        manifest.push_str(&format!(
+            "\n\n[[bin]]\nname = \"{}\"\npath = \"src/bin/{}.rs\"",
+            name, name
+        ));
+    }
+    let viz_name = format!("{}_viz", name);
+    if new_day.viz && !has_bin(&manifest, &viz_name) {
+        manifest.push_str(&format!(
+            "\n\n[[bin]]\nname = \"{}\"\npath = \"src/bin/{}.rs\"\nrequired-features = [\"viz\"]",
+            viz_name, viz_name
+        ));
+    }
+    manifest.push('\n');
+    manifest
+}
+
+fn has_bin(manifest: &str, name: &str) -> bool {
+    let entry = format!("name = \"{}\"", name);
+    manifest.lines().any(|line| line.trim() == entry)
+}
+
+const MODULE_TEMPLATE: &str = r##"//! Day {N}: {TITLE}
+
+use crate::trace::Level;
+use crate::trace_event;
+use crate::{Answer, Context, Solution};
+
+pub struct Day{NN};
+
+fn parse_input(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+fn part1(lines: &[String]) -> i64 {
+    trace_event!(Level::Debug, "read", lines = lines.len());
+    0
+}
+
+fn part2(lines: &[String]) -> i64 {
+    trace_event!(Level::Debug, "read", lines = lines.len());
+    0
+}
+
+impl Solution for Day{NN} {
+    const DAY: u8 = {N};
+    const TITLE: &'static str = {TITLE_LITERAL};
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(lines: &Self::Parsed, _ctx: &Context) -> Answer {
+        part1(lines).into()
+    }
+
+    fn part2(lines: &Self::Parsed, _ctx: &Context) -> Answer {
+        part2(lines).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::example;
+    use crate::Part;
+    // Save the puzzle page as Input/day{NN}.html, run `aoc examples {N}` to
+    // write fixtures/day{NN}.toml and remove the `#[ignore]`s
+    #[test]
+    #[ignore = "fixtures/day{NN}.toml not extracted yet"]
+    fn test_part1() {
+        let example = example({N}, Part::One);
+        let lines = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part1(&lines))), example.answer);
+    }
+    #[test]
+    #[ignore = "fixtures/day{NN}.toml not extracted yet"]
+    fn test_part2() {
+        let example = example({N}, Part::Two);
+        let lines = parse_input(&example.input);
+        assert_eq!(Some(Answer::from(part2(&lines))), example.answer);
+    }
+}
+"##;
+
+const BIN_TEMPLATE: &str = r#"use std::process::ExitCode;
+
+use aoc2025::cli;
+
+fn main() -> ExitCode {
+    cli::run_day_binary({N})
+}
+"#;
+
+const VIZ_TEMPLATE: &str = r#"//! Day {N} visualization
+//!
+//! Loads the day's input, shows its answers and drives a step counter with the
+//! shared playback controls. Replace `VizState::step` with the puzzle's own
+//! simulation and add drawing systems as needed.
+
+use bevy::prelude::*;
+use bevy::window::{PresentMode, WindowResolution};
+use bevy_egui::{egui, EguiContexts, EguiPlugin, EguiPrimaryContextPass};
+
+use aoc2025::viz::{
+    load_input_via_dialog, playback_controls_ui, stats_grid, LoadedInput, PlaybackState,
+};
+use aoc2025::{days, input_path, try_read_input, with_timers_silenced, Context, Part};
+
+const DAY: u8 = {N};
+
+#[derive(Resource, Default)]
+struct VizState {
+    playback: PlaybackState,
+    /// Where the current input came from
+    source: String,
+    input: String,
+    /// `(label, answer)` for each part of the current input
+    answers: Vec<(String, String)>,
+    /// Simulation steps taken since the last reset
+    steps: u64,
+    /// Fractional steps carried over between frames
+    accumulator: f32,
+    last_error: Option<String>,
+}
+
+impl VizState {
+    fn load(&mut self, source: String, input: String) {
+        let day = days::get(DAY).expect("day is registered");
+        let ctx = Context::default();
+        self.answers = with_timers_silenced(|| {
+            let parsed = day.parse(&input);
+            Part::ALL
+                .iter()
+                .map(|&part| (part.label(), day.solve(&parsed, part, &ctx).to_string()))
+                .collect()
+        });
+        self.source = source;
+        self.input = input;
+        self.last_error = None;
+        self.reset();
+    }
+
+    fn reset(&mut self) {
+        self.steps = 0;
+        self.accumulator = 0.0;
+        self.playback.playing = false;
+    }
+
+    fn step(&mut self) {
+        self.steps += 1;
+    }
+}
+
+fn main() {
+    App::new()
+        .insert_resource(ClearColor(Color::srgb(0.05, 0.05, 0.08)))
+        .init_resource::<VizState>()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "AoC 2025 - Day {NN} Visualization".into(),
+                resolution: WindowResolution::new(1280, 720),
+                present_mode: PresentMode::AutoVsync,
+                resizable: true,
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(EguiPlugin::default())
+        .add_systems(Startup, (setup_camera, load_default_input))
+        .add_systems(Update, run_simulation)
+        .add_systems(EguiPrimaryContextPass, ui_system)
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+fn load_default_input(mut state: ResMut<VizState>) {
+    match try_read_input(DAY) {
+        Ok(input) => state.load(input_path(DAY).display().to_string(), input),
+        Err(err) => state.last_error = Some(err.to_string()),
+    }
+}
+
+fn run_simulation(time: Res<Time>, mut state: ResMut<VizState>) {
+    if !state.playback.playing {
+        return;
+    }
+    state.accumulator += time.delta_secs() * state.playback.speed;
+    while state.accumulator >= 1.0 {
+        state.accumulator -= 1.0;
+        state.step();
+    }
+}
+
+fn ui_system(mut contexts: EguiContexts, mut state: ResMut<VizState>) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    egui::Window::new("Controls")
+        .default_pos(egui::pos2(16.0, 16.0))
+        .resizable(false)
+        .show(ctx, |ui| {
+            if ui.button("Load input...").clicked() {
+                if let Some(LoadedInput { path, contents }) = load_input_via_dialog() {
+                    state.load(path.display().to_string(), contents);
+                }
+            }
+            if let Some(err) = &state.last_error {
+                ui.colored_label(egui::Color32::RED, err);
+            } else {
+                ui.label(format!("Source: {}", state.source));
+            }
+            ui.separator();
+
+            let action = playback_controls_ui(ui, &mut state.playback);
+            if action.reset {
+                state.reset();
+            }
+            if action.step_once {
+                state.step();
+            }
+        });
+
+    egui::Window::new("Statistics")
+        .default_pos(egui::pos2(960.0, 16.0))
+        .show(ctx, |ui| {
+            let mut stats = vec![
+                ("Input lines", state.input.lines().count().to_string()),
+                ("Steps", state.steps.to_string()),
+            ];
+            stats.extend(
+                state
+                    .answers
+                    .iter()
+                    .map(|(label, answer)| (label.as_str(), answer.clone())),
+            );
+            stats_grid(ui, &stats);
+        });
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "use crate::Day;\n\npub mod day01;\npub mod day03;\n\nstatic DAYS: &[Day] = &[\n    Day::new::<day01::Day01>(),\n    Day::new::<day03::Day03>(),\n];\n";
+
+    fn new_day(day: u8, viz: bool) -> NewDay {
+        NewDay {
+            day,
+            title: "Gift \"Shop\"".to_string(),
+            viz,
+        }
+    }
+
+    #[test]
+    fn test_templates() {
+        let day = new_day(7, false);
+        let module = day.module_source();
+        assert!(module.starts_with("//! Day 7: Gift \"Shop\"\n"));
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("const TITLE: &'static str = \"Gift \\\"Shop\\\"\";"));
+        assert!(day.bin_source().contains("cli::run_day_binary(7)"));
+        assert!(day.viz_source().contains("Day 07 Visualization"));
+        assert!(!day.viz_source().contains("{N"));
+    }
+
+    #[test]
+    fn test_register_day_in_order() {
+        let registry = register_day(REGISTRY, 2).unwrap();
+        assert_eq!(
+            registry,
+            "use crate::Day;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\nstatic DAYS: &[Day] = &[\n    Day::new::<day01::Day01>(),\n    Day::new::<day02::Day02>(),\n    Day::new::<day03::Day03>(),\n];\n"
+        );
+        let registry = register_day(REGISTRY, 12).unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day12;\n"));
+        assert!(registry.contains("Day03>(),\n    Day::new::<day12::Day12>(),\n];"));
+
+        assert!(register_day(REGISTRY, 3).is_err());
+        assert!(register_day("static DAYS: &[Day] = &[];\n", 3).is_err());
+    }
+
+    #[test]
+    fn test_add_bin_entries() {
+        let manifest = "[package]\nname = \"aoc2025\"\n\n[[bin]]\nname = \"day01\"\npath = \"src/bin/day01.rs\"\n";
+        let updated = add_bin_entries(manifest, &new_day(7, true));
+        assert!(updated.ends_with(
+            "path = \"src/bin/day01.rs\"\n\n[[bin]]\nname = \"day07\"\npath = \"src/bin/day07.rs\"\n\n[[bin]]\nname = \"day07_viz\"\npath = \"src/bin/day07_viz.rs\"\nrequired-features = [\"viz\"]\n"
+        ));
+        // Running it again doesn't duplicate anything
+        assert_eq!(add_bin_entries(&updated, &new_day(7, true)), updated);
+        assert_eq!(add_bin_entries(manifest, &new_day(1, false)), manifest);
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = std::env::temp_dir().join(format!("aoc2025-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::create_dir_all(root.join("src/bin")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), REGISTRY).unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]\nname = \"aoc2025\"\n").unwrap();
+
+        let day = new_day(2, true);
+        let written = create_day(&root, &day).unwrap();
+        assert_eq!(written.len(), 5);
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/day02.rs")).unwrap(),
+            day.module_source()
+        );
+        assert!(root.join("src/bin/day02_viz.rs").exists());
+        assert!(fs::read_to_string(root.join("src/days/mod.rs"))
+            .unwrap()
+            .contains("pub mod day02;"));
+
+        // A second run refuses to overwrite anything
+        assert!(matches!(
+            create_day(&root, &day),
+            Err(AocError::AlreadyExists { .. })
+        ));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
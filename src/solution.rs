@@ -0,0 +1,269 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::generate::Shape;
+use crate::parse::ParseError;
+use crate::rng::Rng;
+use crate::Timer;
+
+/// The name used for the parsing timer
+pub const PARSE_LABEL: &str = "Parse";
+
+/// One of the two halves of a puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in solving order
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The part number as printed in the puzzle (1 or 2)
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    /// The name used for this part's timer (e.g. `Part 1`)
+    pub fn label(self) -> String {
+        format!("Part {}", self.number())
+    }
+
+    /// Parse a part number (`1` or `2`)
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// The answer to one part of a puzzle
+///
+/// Most puzzles have numeric answers, but some ask for a string, so both are
+/// supported. Use `.into()` to build one from the value a solver computed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Options passed to every part of every day
+///
+/// Solvers explain what they are doing through [`crate::trace`] rather than
+/// through the context, so it holds no options yet; build one with
+/// `Context::default()`.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct Context {}
+
+/// The interface every day's solver implements
+///
+/// The input is parsed once and then shared by both parts.
+///
+/// # Example
+/// ```
+/// use aoc2025::{Answer, Context, Solution};
+///
+/// struct Sum;
+///
+/// impl Solution for Sum {
+///     const DAY: u8 = 0;
+///     const TITLE: &'static str = "Sum";
+///     type Parsed = Vec<i64>;
+///
+///     fn parse(input: &str) -> Self::Parsed {
+///         input.lines().map(|l| l.parse().unwrap()).collect()
+///     }
+///
+///     fn part1(numbers: &Self::Parsed, _ctx: &Context) -> Answer {
+///         numbers.iter().sum::<i64>().into()
+///     }
+///
+///     fn part2(numbers: &Self::Parsed, _ctx: &Context) -> Answer {
+///         numbers.iter().product::<i64>().into()
+///     }
+/// }
+///
+/// let parsed = Sum::parse("2\n3\n4");
+/// assert_eq!(Sum::part1(&parsed, &Context::default()), Answer::Number(9));
+/// ```
+pub trait Solution {
+    /// The day number (1-based)
+    const DAY: u8;
+    /// The puzzle title
+    const TITLE: &'static str;
+    /// The parsed form of the input shared by both parts
+    type Parsed: 'static;
+
+    fn parse(input: &str) -> Self::Parsed;
+    fn part1(input: &Self::Parsed, ctx: &Context) -> Answer;
+    fn part2(input: &Self::Parsed, ctx: &Context) -> Answer;
+
+    /// Report every problem that would stop [`Solution::parse`] or either
+    /// part from handling `input`, each with its line and column
+    ///
+    /// Unlike `parse`, this never panics and doesn't stop at the first
+    /// problem. The default accepts any input.
+    fn validate(_input: &str) -> Vec<ParseError> {
+        Vec::new()
+    }
+
+    /// Write a random input that [`Solution::validate`] accepts, sized by
+    /// `shape` (see [`crate::generate`])
+    ///
+    /// Fails on a shape value the day can't honour. The default has no
+    /// generator at all.
+    fn generate(_rng: &mut Rng, _shape: &Shape) -> Result<String, String> {
+        Err(format!("day {} has no input generator", Self::DAY))
+    }
+}
+
+/// Parsed input of a registered day, as returned by [`Day::parse`]
+pub struct ParsedInput {
+    day: u8,
+    inner: Box<dyn Any>,
+}
+
+/// A type-erased entry in the day registry
+///
+/// Built from a [`Solution`] with [`Day::new`], so tooling can call any day by
+/// number without knowing its concrete types.
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    parse: fn(&str) -> Box<dyn Any>,
+    part1: fn(&dyn Any, &Context) -> Answer,
+    part2: fn(&dyn Any, &Context) -> Answer,
+    validate: fn(&str) -> Vec<ParseError>,
+    generate: fn(&mut Rng, &Shape) -> Result<String, String>,
+}
+
+impl Day {
+    /// Create a registry entry for a solution
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            title: S::TITLE,
+            parse: parse_erased::<S>,
+            part1: part1_erased::<S>,
+            part2: part2_erased::<S>,
+            validate: S::validate,
+            generate: S::generate,
+        }
+    }
+
+    /// Parse the input for this day
+    pub fn parse(&self, input: &str) -> ParsedInput {
+        let _timer = Timer::new(PARSE_LABEL);
+        ParsedInput {
+            day: self.number,
+            inner: (self.parse)(input),
+        }
+    }
+
+    /// Solve one part using previously parsed input
+    ///
+    /// # Panics
+    /// Panics if `parsed` was produced by a different day
+    pub fn solve(&self, parsed: &ParsedInput, part: Part, ctx: &Context) -> Answer {
+        assert_eq!(
+            parsed.day, self.number,
+            "parsed input belongs to day {}",
+            parsed.day
+        );
+        let _timer = Timer::new(part.label());
+        match part {
+            Part::One => (self.part1)(parsed.inner.as_ref(), ctx),
+            Part::Two => (self.part2)(parsed.inner.as_ref(), ctx),
+        }
+    }
+
+    /// Check the input for this day, see [`Solution::validate`]
+    pub fn validate(&self, input: &str) -> Vec<ParseError> {
+        (self.validate)(input)
+    }
+
+    /// Generate a random input, see [`Solution::generate`]
+    ///
+    /// Also fails if `shape` sets a key the generator never reads.
+    pub fn generate(&self, rng: &mut Rng, shape: &Shape) -> Result<String, String> {
+        let input = (self.generate)(rng, shape)?;
+        shape.check_unused()?;
+        Ok(input)
+    }
+
+    /// Parse the input and solve a single part
+    pub fn run(&self, input: &str, part: Part, ctx: &Context) -> Answer {
+        let parsed = self.parse(input);
+        self.solve(&parsed, part, ctx)
+    }
+}
+
+fn parse_erased<S: Solution>(input: &str) -> Box<dyn Any> {
+    Box::new(S::parse(input))
+}
+
+fn part1_erased<S: Solution>(parsed: &dyn Any, ctx: &Context) -> Answer {
+    S::part1(downcast::<S>(parsed), ctx)
+}
+
+fn part2_erased<S: Solution>(parsed: &dyn Any, ctx: &Context) -> Answer {
+    S::part2(downcast::<S>(parsed), ctx)
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed {
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .unwrap_or_else(|| panic!("parsed input does not belong to day {}", S::DAY))
+}
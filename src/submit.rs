@@ -0,0 +1,432 @@
+//! Submitting answers and keeping a local history of the verdicts
+//!
+//! Every judged submission is appended to `Input/submissions.tsv` as
+//! `day<TAB>part<TAB>verdict<TAB>answer`. The history is consulted before
+//! posting, so an answer that is already known to be wrong (or is out of the
+//! bounds given by earlier "too high"/"too low" verdicts) is never sent twice.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::client::AocClient;
+use crate::config::Config;
+use crate::{Answer, AocError, Part};
+
+/// The default location of the submission history
+pub fn history_path() -> PathBuf {
+    PathBuf::from("Input/submissions.tsv")
+}
+
+/// The site's response to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about the direction
+    Incorrect,
+    /// Rate limited: try again after this many seconds
+    Wait(u64),
+    /// The part was already solved, so the answer was not judged
+    AlreadySolved,
+    /// A response that could not be classified, with its text
+    Unrecognized(String),
+}
+
+impl Verdict {
+    /// Whether the verdict says something about the answer itself
+    pub fn is_judgement(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::Wait(_) => "wait",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::Unrecognized(_) => "unrecognized",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(Verdict::Correct),
+            "too_high" => Some(Verdict::TooHigh),
+            "too_low" => Some(Verdict::TooLow),
+            "incorrect" => Some(Verdict::Incorrect),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "Wrong: too high"),
+            Verdict::TooLow => write!(f, "Wrong: too low"),
+            Verdict::Incorrect => write!(f, "Wrong"),
+            Verdict::Wait(seconds) => {
+                write!(f, "Rate limited: wait {}s before submitting again", seconds)
+            }
+            Verdict::AlreadySolved => write!(f, "Already solved (or not unlocked yet)"),
+            Verdict::Unrecognized(text) => write!(f, "Unrecognized response: {}", text),
+        }
+    }
+}
+
+/// Classify the HTML page returned after posting an answer
+pub fn parse_verdict(html: &str) -> Verdict {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::Wait(parse_wait_seconds(&text).unwrap_or(60))
+    } else if text.contains("Did you already complete it") {
+        Verdict::AlreadySolved
+    } else if text.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Incorrect
+    } else {
+        Verdict::Unrecognized(text)
+    }
+}
+
+/// The text of the page's `<article>` (or the whole page), without tags
+fn article_text(html: &str) -> String {
+    let body = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse "you have 1m 23s left to wait" into seconds
+fn parse_wait_seconds(text: &str) -> Option<u64> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (number, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(seconds)
+}
+
+/// One judged submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Every judged submission made from this machine
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubmissionHistory {
+    pub entries: Vec<Submission>,
+}
+
+impl SubmissionHistory {
+    /// Load the history, or an empty one if the file doesn't exist
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(AocError::from_io(path, err)),
+        };
+
+        let mut entries = Vec::new();
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let malformed = |message: &str| AocError::Malformed {
+                path: path.to_path_buf(),
+                line: idx + 1,
+                message: message.to_string(),
+            };
+            let fields: Vec<&str> = line.splitn(4, '\t').collect();
+            let [day, part, verdict, answer] = fields[..] else {
+                return Err(malformed("expected day, part, verdict and answer"));
+            };
+            entries.push(Submission {
+                day: day.parse().map_err(|_| malformed("invalid day"))?,
+                part: part_from_str(part).ok_or_else(|| malformed("invalid part"))?,
+                verdict: Verdict::from_key(verdict).ok_or_else(|| malformed("invalid verdict"))?,
+                answer: answer.to_string(),
+            });
+        }
+        Ok(Self { entries })
+    }
+
+    /// Append a judged submission to the file at `path` and to this history
+    pub fn record(
+        &mut self,
+        path: impl AsRef<Path>,
+        submission: Submission,
+    ) -> Result<(), AocError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|err| AocError::from_io(dir, err))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|err| AocError::from_io(path, err))?;
+        writeln!(
+            file,
+            "{:02}\t{}\t{}\t{}",
+            submission.day,
+            submission.part,
+            submission.verdict.key(),
+            submission.answer
+        )
+        .map_err(|err| AocError::from_io(path, err))?;
+        self.entries.push(submission);
+        Ok(())
+    }
+
+    /// Why submitting `answer` would be pointless, if the history already tells
+    pub fn refusal_reason(&self, day: u8, part: Part, answer: &Answer) -> Option<String> {
+        let answer_text = answer.to_string();
+        let answer_number = match answer {
+            Answer::Number(n) => Some(*n),
+            Answer::Text(_) => None,
+        };
+
+        for entry in self
+            .entries
+            .iter()
+            .filter(|e| e.day == day && e.part == part)
+        {
+            if entry.verdict == Verdict::Correct {
+                return Some(format!(
+                    "part {} was already solved with {}",
+                    part, entry.answer
+                ));
+            }
+            if entry.answer == answer_text {
+                return Some(format!(
+                    "{} was already submitted: {}",
+                    answer_text, entry.verdict
+                ));
+            }
+            let (Some(answer), Ok(previous)) = (answer_number, entry.answer.parse::<i64>()) else {
+                continue;
+            };
+            match entry.verdict {
+                Verdict::TooHigh if answer >= previous => {
+                    return Some(format!("{} was too high, so {} is too", previous, answer))
+                }
+                Verdict::TooLow if answer <= previous => {
+                    return Some(format!("{} was too low, so {} is too", previous, answer))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+fn part_from_str(value: &str) -> Option<Part> {
+    value.parse::<u8>().ok().and_then(Part::from_number)
+}
+
+/// Post an answer unless the history shows it is already known to be wrong
+///
+/// Judged verdicts are appended to the history at `history_path`.
+pub fn submit_answer(
+    config: &Config,
+    history: &mut SubmissionHistory,
+    history_path: &Path,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Verdict, AocError> {
+    if let Some(reason) = history.refusal_reason(day, part, answer) {
+        return Err(AocError::SubmissionRefused(reason));
+    }
+
+    let client = AocClient::new(config)?;
+    let level = part.to_string();
+    let answer_text = answer.to_string();
+    let html = client.post_form(
+        &format!("/{}/day/{}/answer", config.year, day),
+        &[("level", &level), ("answer", &answer_text)],
+    )?;
+    let verdict = parse_verdict(&html);
+
+    if verdict.is_judgement() {
+        history.record(
+            history_path,
+            Submission {
+                day,
+                part,
+                verdict: verdict.clone(),
+                answer: answer_text,
+            },
+        )?;
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub::StubServer;
+    use std::time::Duration;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            article
+        )
+    }
+
+    #[test]
+    fn test_parse_verdicts() {
+        assert_eq!(
+            parse_verdict(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            parse_verdict(&page("That's not the right answer. If you're stuck...")),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again. You have 1m 23s left to wait."
+            )),
+            Verdict::Wait(83)
+        );
+        assert_eq!(
+            parse_verdict(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(
+            parse_verdict("<p>Something else</p>"),
+            Verdict::Unrecognized("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn test_refusal_reasons() {
+        let mut history = SubmissionHistory::default();
+        let mut push = |answer: &str, verdict| {
+            history.entries.push(Submission {
+                day: 1,
+                part: Part::One,
+                verdict,
+                answer: answer.to_string(),
+            })
+        };
+        push("500", Verdict::TooHigh);
+        push("100", Verdict::TooLow);
+        push("250", Verdict::Incorrect);
+
+        let reason = |n: i64| history.refusal_reason(1, Part::One, &Answer::Number(n));
+        assert!(reason(500).is_some());
+        assert!(reason(600).is_some());
+        assert!(reason(100).is_some());
+        assert!(reason(50).is_some());
+        assert!(reason(250).is_some());
+        assert!(reason(300).is_none());
+        assert!(history
+            .refusal_reason(1, Part::Two, &Answer::Number(500))
+            .is_none());
+    }
+
+    #[test]
+    fn test_submit_against_stub() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-submit-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let history_file = dir.join("submissions.tsv");
+        let server = StubServer::start(vec![
+            (
+                200,
+                &page("That's not the right answer; your answer is too low."),
+            ),
+            (200, &page("That's the right answer!")),
+        ]);
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: server.base_url.clone(),
+            min_delay: Duration::ZERO,
+            input_dir: dir.clone(),
+            ..Config::default()
+        };
+
+        let mut history = SubmissionHistory::default();
+        let mut submit = |answer: i64| {
+            submit_answer(
+                &config,
+                &mut history,
+                &history_file,
+                5,
+                Part::Two,
+                &Answer::Number(answer),
+            )
+        };
+        assert_eq!(submit(10).unwrap(), Verdict::TooLow);
+        assert!(matches!(submit(7), Err(AocError::SubmissionRefused(_))));
+        assert_eq!(submit(42).unwrap(), Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2025/day/5/answer "));
+        assert!(requests[0].ends_with("level=2&answer=10"));
+
+        let reloaded = SubmissionHistory::load(&history_file).unwrap();
+        assert_eq!(reloaded, history);
+        assert_eq!(reloaded.entries[1].verdict, Verdict::Correct);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,752 @@
+//! Run many days at once and summarise their answers and timings in a table
+//!
+//! Days run in parallel, one per worker thread. A day that panics is caught
+//! and reported in its row rather than taking the rest of the run down with
+//! it. Besides the aligned table, reports render as JSON, CSV or Markdown
+//! (see [`Format`]) for scripts and generated docs.
+
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::json;
+use crate::{Answer, AocError, Context, Day, Part};
+
+/// How a day's run ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// Parsing or a part panicked; holds the first panic's message
+    Panicked(String),
+    /// The input file doesn't exist
+    MissingInput,
+    /// The input file exists but couldn't be used
+    BadInput(String),
+}
+
+impl Status {
+    /// A short machine-readable name: `ok`, `panicked`, `missing-input` or
+    /// `bad-input`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Panicked(_) => "panicked",
+            Status::MissingInput => "missing-input",
+            Status::BadInput(_) => "bad-input",
+        }
+    }
+
+    /// The panic or error message, if there is one
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Status::Panicked(message) | Status::BadInput(message) => Some(message),
+            Status::Ok | Status::MissingInput => None,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Panicked(message) => write!(f, "panicked: {}", message),
+            Status::MissingInput => write!(f, "missing input"),
+            Status::BadInput(message) => write!(f, "bad input: {}", message),
+        }
+    }
+}
+
+/// The outcome of one part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub part: Part,
+    /// `None` if the part panicked
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+}
+
+/// The outcome of running one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: u8,
+    pub title: &'static str,
+    /// `None` if the input was unavailable or parsing panicked
+    pub parse_time: Option<Duration>,
+    /// The parts that ran, in the order they were requested
+    pub parts: Vec<PartReport>,
+    pub status: Status,
+}
+
+impl DayReport {
+    pub fn part(&self, part: Part) -> Option<&PartReport> {
+        self.parts.iter().find(|report| report.part == part)
+    }
+
+    /// Time spent parsing and solving
+    pub fn total_time(&self) -> Duration {
+        self.parse_time.unwrap_or_default() + self.parts.iter().map(|p| p.elapsed).sum::<Duration>()
+    }
+}
+
+thread_local! {
+    /// Whether [`catch`] is running on this thread, so the hook should record
+    /// a panic's location instead of reporting it
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    /// Where the last captured panic on this thread happened
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Panics the installed hook handed on to the previous one, so tests can
+/// tell that panics outside [`catch`] are still reported
+#[cfg(test)]
+static DELEGATED: AtomicUsize = AtomicUsize::new(0);
+
+/// Chain a hook in front of the current one, once per process
+///
+/// The hook is never removed again: swapping hooks around a run would race
+/// with other threads doing the same, and would swallow panics on threads
+/// that have nothing to do with the run.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CAPTURING.try_with(Cell::get).unwrap_or(false) {
+                let location = info.location().map(|location| location.to_string());
+                PANIC_LOCATION.with(|slot| *slot.borrow_mut() = location);
+            } else {
+                #[cfg(test)]
+                DELEGATED.fetch_add(1, Ordering::Relaxed);
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Run `f`, turning a panic into its message
+fn catch<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    install_hook();
+    let was_capturing = CAPTURING.with(|capturing| capturing.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.with(|capturing| capturing.set(was_capturing));
+    result.map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "non-string panic payload".to_string());
+        match PANIC_LOCATION.with(|location| location.borrow_mut().take()) {
+            Some(location) => format!("{} ({})", message, location),
+            None => message,
+        }
+    })
+}
+
+/// Parse `input` and solve `parts` of `day` on the current thread, catching
+/// any panic
+///
+/// A part that panics doesn't stop the later parts from running, but a panic
+/// while parsing leaves nothing to solve.
+pub fn run_day(day: &Day, input: Result<String, AocError>, parts: &[Part]) -> DayReport {
+    let mut report = DayReport {
+        day: day.number,
+        title: day.title,
+        parse_time: None,
+        parts: Vec::new(),
+        status: Status::Ok,
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(AocError::NotFound { .. }) => {
+            report.status = Status::MissingInput;
+            return report;
+        }
+        Err(err) => {
+            report.status = Status::BadInput(err.to_string());
+            return report;
+        }
+    };
+
+    let start = Instant::now();
+    let parsed = match catch(|| day.parse(&input)) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            report.status = Status::Panicked(message);
+            return report;
+        }
+    };
+    report.parse_time = Some(start.elapsed());
+
+    let ctx = Context::default();
+    for &part in parts {
+        let start = Instant::now();
+        let answer = catch(|| day.solve(&parsed, part, &ctx));
+        let elapsed = start.elapsed();
+        let answer = match answer {
+            Ok(answer) => Some(answer),
+            Err(message) => {
+                if report.status == Status::Ok {
+                    report.status = Status::Panicked(message);
+                }
+                None
+            }
+        };
+        report.parts.push(PartReport {
+            part,
+            answer,
+            elapsed,
+        });
+    }
+    report
+}
+
+/// Run `parts` of every day in `days` on up to `jobs` threads
+///
+/// `load` reads each day's input. Panics in the days are only reported
+/// through the returned rows, not printed. Reports come back sorted by day.
+pub fn run_all<F>(days: &[&Day], parts: &[Part], jobs: usize, load: F) -> Vec<DayReport>
+where
+    F: Fn(&Day) -> Result<String, AocError> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(Vec::with_capacity(days.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let report = run_day(day, load(day), parts);
+                    reports
+                        .lock()
+                        .unwrap_or_else(|err| err.into_inner())
+                        .push(report);
+                }
+            });
+        }
+    });
+
+    let mut reports = reports.into_inner().unwrap_or_else(|err| err.into_inner());
+    reports.sort_by_key(|report| report.day);
+    reports
+}
+
+/// How to print a run's results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The runner's usual human-readable output
+    Text,
+    /// A JSON array with one object per day and part
+    Json,
+    /// A header line, then one line per day and part
+    Csv,
+    /// A GitHub-flavoured Markdown table with one row per day
+    Markdown,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            other => Err(format!(
+                "Invalid format '{}': expected text, json, csv or markdown",
+                other
+            )),
+        }
+    }
+}
+
+/// Render reports in `format`, with [`render_table`] standing in for text
+pub fn render(reports: &[DayReport], parts: &[Part], format: Format) -> String {
+    match format {
+        Format::Text => render_table(reports, parts),
+        Format::Json => render_json(reports, parts),
+        Format::Csv => render_csv(reports, parts),
+        Format::Markdown => render_markdown(reports, parts),
+    }
+}
+
+/// One day and part of a report, as the JSON and CSV formats list them
+struct Record<'a> {
+    report: &'a DayReport,
+    part: Part,
+    answer: Option<&'a Answer>,
+    elapsed: Option<Duration>,
+    status: &'a Status,
+}
+
+/// A record for every requested part of every day, including parts that
+/// never ran because the day had no input or failed to parse
+fn records<'a>(reports: &'a [DayReport], parts: &'a [Part]) -> impl Iterator<Item = Record<'a>> {
+    reports.iter().flat_map(move |report| {
+        parts.iter().map(move |&part| {
+            let ran = report.part(part);
+            let answer = ran.and_then(|p| p.answer.as_ref());
+            Record {
+                report,
+                part,
+                answer,
+                elapsed: ran.map(|p| p.elapsed),
+                // A part that answered is fine even if another part panicked
+                status: if answer.is_some() {
+                    &Status::Ok
+                } else {
+                    &report.status
+                },
+            }
+        })
+    })
+}
+
+/// Render reports as a JSON array of `{day, title, part, answer, parse_ns,
+/// elapsed_ns, status, message}` objects
+///
+/// Numeric answers stay numbers; a missing answer, time or message is `null`.
+pub fn render_json(reports: &[DayReport], parts: &[Part]) -> String {
+    let nanos = |elapsed: Option<Duration>| {
+        elapsed.map_or("null".to_string(), |d| d.as_nanos().to_string())
+    };
+    let items: Vec<String> = records(reports, parts)
+        .map(|record| {
+            let answer = match record.answer {
+                Some(Answer::Number(n)) => n.to_string(),
+                Some(Answer::Text(text)) => json::string(text),
+                None => "null".to_string(),
+            };
+            format!(
+                "{{\"day\":{},\"title\":{},\"part\":{},\"answer\":{},\"parse_ns\":{},\
+                 \"elapsed_ns\":{},\"status\":{},\"message\":{}}}",
+                record.report.day,
+                json::string(record.report.title),
+                record.part.number(),
+                answer,
+                nanos(record.report.parse_time),
+                nanos(record.elapsed),
+                json::string(record.status.kind()),
+                record
+                    .status
+                    .message()
+                    .map_or("null".to_string(), json::string)
+            )
+        })
+        .collect();
+    format!("[{}]\n", items.join(",\n "))
+}
+
+/// Render reports as CSV with the same columns as [`render_json`]; missing
+/// values are empty fields
+pub fn render_csv(reports: &[DayReport], parts: &[Part]) -> String {
+    fn field(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+    let nanos =
+        |elapsed: Option<Duration>| elapsed.map_or(String::new(), |d| d.as_nanos().to_string());
+
+    let mut out = "day,title,part,answer,parse_ns,elapsed_ns,status,message\n".to_string();
+    for record in records(reports, parts) {
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{},{},{},{}",
+            record.report.day,
+            field(record.report.title),
+            record.part.number(),
+            field(&record.answer.map_or(String::new(), Answer::to_string)),
+            nanos(record.report.parse_time),
+            nanos(record.elapsed),
+            record.status.kind(),
+            field(record.status.message().unwrap_or_default())
+        );
+    }
+    out
+}
+
+/// Render reports as a Markdown table with the columns of [`render_table`]
+/// and a total time, ready to paste into a README
+pub fn render_markdown(reports: &[DayReport], parts: &[Part]) -> String {
+    // Pipes and line breaks would end a cell or the row early
+    let cell = |text: &str| {
+        text.replace('|', "\\|")
+            .replace("\r\n", "<br>")
+            .replace(['\r', '\n'], "<br>")
+    };
+    let time =
+        |elapsed: Option<Duration>| elapsed.map_or("-".to_string(), |d| format!("{:.2?}", d));
+
+    let mut header = vec!["Day".to_string(), "Title".to_string()];
+    header.extend(parts.iter().map(|part| part.label()));
+    header.push("Time".to_string());
+    header.push("Status".to_string());
+    let mut out = format!("| {} |\n", header.join(" | "));
+    let rule: Vec<&str> = header
+        .iter()
+        .enumerate()
+        .map(|(col, _)| match col {
+            1 => "---",
+            _ if col == header.len() - 1 => "---",
+            _ => "---:",
+        })
+        .collect();
+    let _ = writeln!(out, "| {} |", rule.join(" | "));
+
+    for report in reports {
+        let mut row = vec![report.day.to_string(), cell(report.title)];
+        row.extend(parts.iter().map(|&part| {
+            report
+                .part(part)
+                .and_then(|p| p.answer.as_ref())
+                .map_or("-".to_string(), |answer| {
+                    format!("`{}`", cell(&answer.to_string()))
+                })
+        }));
+        row.push(time(report.parse_time.map(|_| report.total_time())));
+        row.push(cell(&report.status.to_string()));
+        let _ = writeln!(out, "| {} |", row.join(" | "));
+    }
+    out
+}
+
+/// Render reports as an aligned table, one row per day, followed by totals
+pub fn render_table(reports: &[DayReport], parts: &[Part]) -> String {
+    let time =
+        |elapsed: Option<Duration>| elapsed.map_or("-".to_string(), |d| format!("{:.2?}", d));
+
+    let mut header = vec!["Day".to_string(), "Title".to_string()];
+    header.extend(parts.iter().map(|part| part.label()));
+    header.push("Parse".to_string());
+    header.extend(parts.iter().map(|part| format!("{} time", part.label())));
+    header.push("Status".to_string());
+
+    let mut rows = vec![header];
+    for report in reports {
+        let mut row = vec![format!("{:02}", report.day), report.title.to_string()];
+        row.extend(parts.iter().map(
+            |&part| match report.part(part).and_then(|p| p.answer.as_ref()) {
+                Some(answer) => answer.to_string(),
+                None => "-".to_string(),
+            },
+        ));
+        row.push(time(report.parse_time));
+        row.extend(
+            parts
+                .iter()
+                .map(|&part| time(report.part(part).map(|p| p.elapsed))),
+        );
+        // Keep multi-line panic messages, such as a failed `assert_eq!`, on their row
+        let status = report.status.to_string().replace("\r\n", " ");
+        row.push(status.replace(['\r', '\n'], " "));
+        rows.push(row);
+    }
+
+    // The status column is last and free-form, so it isn't padded
+    let columns = rows[0].len();
+    let widths: Vec<usize> = (0..columns)
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut out = String::new();
+    for (idx, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(col, (cell, &width))| match col {
+                // Titles and the status read left to right; everything else is a number
+                1 => format!("{:<width$}", cell, width = width),
+                _ if col == columns - 1 => cell.clone(),
+                _ => format!("{:>width$}", cell, width = width),
+            })
+            .collect();
+        let _ = writeln!(out, "{}", cells.join("  ").trim_end());
+        if idx == 0 {
+            let rule: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+            let _ = writeln!(out, "{}", rule.join("  "));
+        }
+    }
+
+    let count = |f: fn(&Status) -> bool| reports.iter().filter(|r| f(&r.status)).count();
+    let total: Duration = reports.iter().map(DayReport::total_time).sum();
+    let _ = writeln!(
+        out,
+        "\n{} days: {} ok, {} panicked, {} without input, {:.2?} total",
+        reports.len(),
+        count(|status| *status == Status::Ok),
+        count(|status| matches!(status, Status::Panicked(_))),
+        count(|status| matches!(status, Status::MissingInput | Status::BadInput(_))),
+        total
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+    use std::path::PathBuf;
+    use std::sync::atomic::AtomicBool;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sum";
+        type Parsed = Vec<i64>;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.lines().map(|l| l.parse().unwrap()).collect()
+        }
+
+        fn part1(numbers: &Self::Parsed, _ctx: &Context) -> Answer {
+            numbers.iter().sum::<i64>().into()
+        }
+
+        fn part2(numbers: &Self::Parsed, _ctx: &Context) -> Answer {
+            numbers.iter().product::<i64>().into()
+        }
+    }
+
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Broken";
+        type Parsed = ();
+
+        fn parse(_input: &str) -> Self::Parsed {}
+
+        fn part1(_: &Self::Parsed, _ctx: &Context) -> Answer {
+            panic!("part 1 is unfinished")
+        }
+
+        fn part2(_: &Self::Parsed, _ctx: &Context) -> Answer {
+            "fine".into()
+        }
+    }
+
+    /// Part 1 holds its worker until [`RELEASE`] is set
+    struct Blocking;
+
+    static STARTED: AtomicBool = AtomicBool::new(false);
+    static RELEASE: AtomicBool = AtomicBool::new(false);
+
+    impl Solution for Blocking {
+        const DAY: u8 = 3;
+        const TITLE: &'static str = "Blocking";
+        type Parsed = ();
+
+        fn parse(_input: &str) -> Self::Parsed {}
+
+        fn part1(_: &Self::Parsed, _ctx: &Context) -> Answer {
+            STARTED.store(true, Ordering::SeqCst);
+            let start = Instant::now();
+            while !RELEASE.load(Ordering::SeqCst) && start.elapsed() < Duration::from_secs(10) {
+                thread::yield_now();
+            }
+            "released".into()
+        }
+
+        fn part2(_: &Self::Parsed, _ctx: &Context) -> Answer {
+            "done".into()
+        }
+    }
+
+    static SUM: Day = Day::new::<Sum>();
+    static BROKEN: Day = Day::new::<Broken>();
+    static BLOCKING: Day = Day::new::<Blocking>();
+
+    #[test]
+    fn test_run_day() {
+        let report = run_day(&SUM, Ok("2\n3\n4".to_string()), &Part::ALL);
+        assert_eq!(report.status, Status::Ok);
+        assert!(report.parse_time.is_some());
+        assert_eq!(
+            report.part(Part::Two).unwrap().answer,
+            Some(Answer::Number(24))
+        );
+
+        let missing = AocError::NotFound {
+            path: PathBuf::from("Input/day01.txt"),
+        };
+        let report = run_day(&SUM, Err(missing), &Part::ALL);
+        assert_eq!(report.status, Status::MissingInput);
+        assert!(report.parts.is_empty());
+    }
+
+    #[test]
+    fn test_run_all_survives_panics() {
+        let reports = run_all(&[&BROKEN, &SUM], &Part::ALL, 4, |day| {
+            Ok(format!("{}\n5", day.number))
+        });
+        assert_eq!(
+            reports.iter().map(|r| r.day).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            reports[0].part(Part::One).unwrap().answer,
+            Some(Answer::Number(6))
+        );
+
+        let broken = &reports[1];
+        let Status::Panicked(message) = &broken.status else {
+            panic!("expected a panic, got {}", broken.status);
+        };
+        assert!(message.starts_with("part 1 is unfinished (src/summary.rs:"));
+        // Part 2 still ran after part 1 panicked
+        assert_eq!(broken.part(Part::One).unwrap().answer, None);
+        assert_eq!(broken.part(Part::Two).unwrap().answer, Some("fine".into()));
+    }
+
+    #[test]
+    fn test_render_table() {
+        let reports = run_all(&[&SUM, &BROKEN], &[Part::One], 1, |day| match day.number {
+            1 => Ok("2\n3".to_string()),
+            _ => Err(AocError::NotFound {
+                path: PathBuf::from("Input/day02.txt"),
+            }),
+        });
+        let table = render_table(&reports, &[Part::One]);
+        let lines: Vec<&str> = table.lines().collect();
+        let words = |line: &str| {
+            line.split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            words(lines[0]),
+            ["Day", "Title", "Part", "1", "Parse", "Part", "1", "time", "Status"]
+        );
+        assert!(lines[1].starts_with("---  "));
+        let row = words(lines[2]);
+        assert_eq!(row[..3], ["01", "Sum", "5"]);
+        assert_eq!(row[5], "ok");
+        assert_eq!(
+            words(lines[3]),
+            ["02", "Broken", "-", "-", "-", "missing", "input"]
+        );
+        // Columns line up, counting characters since times may contain 'µ'
+        let column = |line: &str, word: &str| line[..line.find(word).unwrap()].chars().count();
+        assert_eq!(column(lines[0], "Status"), column(lines[2], "ok"));
+        assert_eq!(
+            lines[5],
+            format!(
+                "2 days: 1 ok, 0 panicked, 1 without input, {:.2?} total",
+                reports[0].total_time()
+            )
+        );
+    }
+
+    #[test]
+    fn test_machine_readable_formats() {
+        let reports = run_all(&[&SUM, &BROKEN], &Part::ALL, 1, |day| match day.number {
+            1 => Ok("2\n3".to_string()),
+            _ => Ok(String::new()),
+        });
+
+        let json = render_json(&reports, &Part::ALL);
+        let lines: Vec<&str> = json.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0]
+            .starts_with("[{\"day\":1,\"title\":\"Sum\",\"part\":1,\"answer\":5,\"parse_ns\":"));
+        assert!(lines[0].ends_with(",\"status\":\"ok\",\"message\":null},"));
+        assert!(lines[2].contains("\"part\":1,\"answer\":null,"));
+        assert!(lines[2].contains("\"status\":\"panicked\",\"message\":\"part 1 is unfinished ("));
+        // Part 2 of the broken day still answered
+        assert!(lines[3].contains("\"answer\":\"fine\","));
+        assert!(lines[3].ends_with("\"status\":\"ok\",\"message\":null}]"));
+
+        let csv = render_csv(&reports, &[Part::One]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "day,title,part,answer,parse_ns,elapsed_ns,status,message"
+        );
+        assert!(lines[1].starts_with("1,Sum,1,5,"));
+        assert!(lines[1].ends_with(",ok,"));
+        assert!(lines[2].starts_with("2,Broken,1,,"));
+        assert!(lines[2].contains(",panicked,part 1 is unfinished ("));
+
+        let markdown = render_markdown(&reports, &Part::ALL);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(
+            lines[0],
+            "| Day | Title | Part 1 | Part 2 | Time | Status |"
+        );
+        assert_eq!(lines[1], "| ---: | --- | ---: | ---: | ---: | --- |");
+        assert!(lines[2].starts_with("| 1 | Sum | `5` | `6` | "));
+        assert!(lines[3].starts_with("| 2 | Broken | - | `fine` | "));
+
+        assert_eq!("md".parse(), Ok(Format::Markdown));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_multi_line_panics_stay_on_their_row() {
+        let message = "assertion `left == right` failed\n  left: 1\r\n right: 2 (src/x.rs:1:5)";
+        let reports = [DayReport {
+            day: 2,
+            title: "Broken",
+            parse_time: None,
+            parts: Vec::new(),
+            status: Status::Panicked(message.to_string()),
+        }];
+
+        let markdown = render_markdown(&reports, &[Part::One]);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[2],
+            "| 2 | Broken | - | - | panicked: assertion `left == right` failed<br>  left: 1<br> \
+             right: 2 (src/x.rs:1:5) |"
+        );
+
+        let table = render_table(&reports, &[Part::One]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[2].ends_with(
+            "panicked: assertion `left == right` failed   left: 1  right: 2 (src/x.rs:1:5)"
+        ));
+        assert_eq!(lines[3], "");
+    }
+
+    #[test]
+    fn test_other_threads_panics_are_still_reported() {
+        thread::scope(|scope| {
+            let run = scope
+                .spawn(|| run_all(&[&BLOCKING, &BROKEN], &Part::ALL, 2, |_| Ok(String::new())));
+            while !STARTED.load(Ordering::SeqCst) {
+                thread::yield_now();
+            }
+            // The runner is busy, so its hook is in place while this panics
+            let before = DELEGATED.load(Ordering::SeqCst);
+            let outside = thread::spawn(|| panic!("unrelated panic")).join();
+            assert!(outside.is_err());
+            assert!(DELEGATED.load(Ordering::SeqCst) > before);
+            RELEASE.store(true, Ordering::SeqCst);
+
+            let reports = run.join().unwrap();
+            // The runner's own panic still comes back with its location
+            let message = reports[0].status.message().unwrap();
+            assert!(message.starts_with("part 1 is unfinished (src/summary.rs:"));
+            assert_eq!(
+                reports[1].part(Part::One).unwrap().answer,
+                Some("released".into())
+            );
+        });
+    }
+}
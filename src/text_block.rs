@@ -0,0 +1,301 @@
+//! Fixed-width text read as a matrix of bytes
+//!
+//! Some puzzles lay their input out in columns, or draw it as ASCII art,
+//! where what matters is which character sits above which. [`TextBlock`]
+//! pads ragged lines with spaces into a rectangle, and [`BlockView`] reads
+//! any rectangular part of it by rows or columns, in either direction. Views
+//! split on blank rows or columns and remember where they came from, so
+//! results can be mapped back to positions in the original text.
+//!
+//! Inputs are expected to be ASCII: each byte is one column.
+
+use std::fmt;
+use std::iter::{Copied, StepBy, Take};
+use std::ops::Range;
+use std::slice;
+
+/// The byte lines are padded with, and that counts as blank
+pub const PAD: u8 = b' ';
+
+/// Lines of text padded with spaces to a common width
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextBlock {
+    bytes: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+impl TextBlock {
+    pub fn new(text: &str) -> Self {
+        let lines: Vec<&str> = text.lines().collect();
+        let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let mut bytes = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            bytes.extend_from_slice(line.as_bytes());
+            bytes.resize(bytes.len() + width - line.len(), PAD);
+        }
+        Self {
+            bytes,
+            width,
+            height: lines.len(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// A view of the whole block
+    pub fn view(&self) -> BlockView<'_> {
+        BlockView {
+            source: self,
+            top: 0,
+            left: 0,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// A rectangular part of a [`TextBlock`]
+///
+/// Coordinates passed to a view are relative to its top-left corner; use
+/// [`BlockView::to_source`] to turn them into positions in the original text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockView<'a> {
+    source: &'a TextBlock,
+    top: usize,
+    left: usize,
+    width: usize,
+    height: usize,
+}
+
+/// The bytes of one column of a view, top to bottom
+pub type Column<'a> = Copied<Take<StepBy<slice::Iter<'a, u8>>>>;
+
+impl<'a> BlockView<'a> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Where the view's top-left corner is in the source text: `(row, col)`
+    pub fn origin(&self) -> (usize, usize) {
+        (self.top, self.left)
+    }
+
+    /// Map a position in the view to the same position in the source text
+    pub fn to_source(&self, row: usize, col: usize) -> (usize, usize) {
+        (self.top + row, self.left + col)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        (row < self.height && col < self.width).then(|| self.row(row)[col])
+    }
+
+    /// The bytes of one row, left to right
+    ///
+    /// # Panics
+    /// Panics if `row` is out of bounds
+    pub fn row(&self, row: usize) -> &'a [u8] {
+        assert!(row < self.height, "row {} out of bounds", row);
+        let start = (self.top + row) * self.source.width + self.left;
+        &self.source.bytes[start..start + self.width]
+    }
+
+    /// The bytes of one column, top to bottom (`.rev()` for bottom to top)
+    ///
+    /// # Panics
+    /// Panics if `col` is out of bounds
+    pub fn column(&self, col: usize) -> Column<'a> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        let start = self.top * self.source.width + self.left + col;
+        // A view with no rows may start past the end of the source
+        self.source
+            .bytes
+            .get(start..)
+            .unwrap_or(&[])
+            .iter()
+            .step_by(self.source.width)
+            .take(self.height)
+            .copied()
+    }
+
+    /// Every row, top to bottom (`.rev()` for bottom to top)
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [u8]> + ExactSizeIterator {
+        let view = *self;
+        (0..self.height).map(move |row| view.row(row))
+    }
+
+    /// Every column, left to right (`.rev()` for right to left)
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = Column<'a>> + ExactSizeIterator {
+        let view = *self;
+        (0..self.width).map(move |col| view.column(col))
+    }
+
+    /// The part of this view covering `rows` × `cols`
+    ///
+    /// # Panics
+    /// Panics if the ranges reach outside the view
+    pub fn sub(&self, rows: Range<usize>, cols: Range<usize>) -> BlockView<'a> {
+        assert!(
+            rows.start <= rows.end && rows.end <= self.height,
+            "rows {:?} out of bounds",
+            rows
+        );
+        assert!(
+            cols.start <= cols.end && cols.end <= self.width,
+            "columns {:?} out of bounds",
+            cols
+        );
+        BlockView {
+            source: self.source,
+            top: self.top + rows.start,
+            left: self.left + cols.start,
+            width: cols.len(),
+            height: rows.len(),
+        }
+    }
+
+    pub fn is_blank_row(&self, row: usize) -> bool {
+        self.row(row).iter().all(|&b| b == PAD)
+    }
+
+    pub fn is_blank_column(&self, col: usize) -> bool {
+        self.column(col).all(|b| b == PAD)
+    }
+
+    /// Split into the full-height blocks between blank columns, left to right
+    pub fn split_columns(&self) -> Vec<BlockView<'a>> {
+        runs(self.width, |col| self.is_blank_column(col))
+            .map(|cols| self.sub(0..self.height, cols))
+            .collect()
+    }
+
+    /// Split into the full-width blocks between blank rows, top to bottom
+    pub fn split_rows(&self) -> Vec<BlockView<'a>> {
+        runs(self.height, |row| self.is_blank_row(row))
+            .map(|rows| self.sub(rows, 0..self.width))
+            .collect()
+    }
+
+    /// Shrink the view to the smallest one holding all its non-blank bytes
+    pub fn trim(&self) -> BlockView<'a> {
+        let rows = span(self.height, |row| self.is_blank_row(row));
+        let cols = span(self.width, |col| self.is_blank_column(col));
+        match (rows, cols) {
+            (Some(rows), Some(cols)) => self.sub(rows, cols),
+            _ => self.sub(0..0, 0..0),
+        }
+    }
+}
+
+/// Renders the view's rows as lines of text
+impl fmt::Display for BlockView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+/// The maximal ranges of `0..len` whose indices are not blank
+fn runs(len: usize, is_blank: impl Fn(usize) -> bool) -> impl Iterator<Item = Range<usize>> {
+    let mut idx = 0;
+    std::iter::from_fn(move || {
+        while idx < len && is_blank(idx) {
+            idx += 1;
+        }
+        let start = idx;
+        while idx < len && !is_blank(idx) {
+            idx += 1;
+        }
+        (start < idx).then_some(start..idx)
+    })
+}
+
+/// The range from the first to the last non-blank index of `0..len`
+fn span(len: usize, is_blank: impl Fn(usize) -> bool) -> Option<Range<usize>> {
+    let first = (0..len).find(|&idx| !is_blank(idx))?;
+    let last = (0..len).rev().find(|&idx| !is_blank(idx))?;
+    Some(first..last + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "12  x\n3\n\n 45 yz\n";
+
+    fn text(bytes: impl IntoIterator<Item = u8>) -> String {
+        bytes.into_iter().map(char::from).collect()
+    }
+
+    #[test]
+    fn test_pads_ragged_lines() {
+        let block = TextBlock::new(TEXT);
+        assert_eq!((block.width(), block.height()), (6, 4));
+        let view = block.view();
+        assert_eq!(view.row(1), b"3     ");
+        assert_eq!(view.get(3, 5), Some(b'z'));
+        assert_eq!(view.get(4, 0), None);
+        assert_eq!(view.to_string(), "12  x \n3     \n      \n 45 yz");
+    }
+
+    #[test]
+    fn test_rows_and_columns_both_ways() {
+        let block = TextBlock::new(TEXT);
+        let view = block.view();
+        let columns: Vec<String> = view.columns().rev().take(2).map(text).collect();
+        assert_eq!(columns, vec!["   z", "x  y"]);
+        assert_eq!(text(view.column(1).rev()), "4  2");
+        let rows: Vec<String> = view
+            .rows()
+            .rev()
+            .map(|row| text(row.iter().copied()))
+            .collect();
+        assert_eq!(rows[0], " 45 yz");
+    }
+
+    #[test]
+    fn test_split_and_source_coordinates() {
+        let block = TextBlock::new(TEXT);
+        let view = block.view();
+
+        let blocks = view.split_columns();
+        let origins: Vec<_> = blocks.iter().map(BlockView::origin).collect();
+        assert_eq!(origins, vec![(0, 0), (0, 4)]);
+        assert_eq!(blocks[0].to_string(), "12 \n3  \n   \n 45");
+        assert_eq!(blocks[1].to_source(3, 1), (3, 5));
+
+        let blocks = view.split_rows();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[1].origin(), (3, 0));
+
+        // Splitting a split block keeps mapping to the original text
+        let inner = blocks[1].split_columns();
+        assert_eq!(inner[1].origin(), (3, 4));
+        assert_eq!(inner[1].to_string(), "yz");
+    }
+
+    #[test]
+    fn test_trim() {
+        let block = TextBlock::new("\n   \n  ab\n  c\n\n");
+        let trimmed = block.view().trim();
+        assert_eq!(trimmed.origin(), (2, 2));
+        assert_eq!(trimmed.to_string(), "ab\nc ");
+        assert_eq!(TextBlock::new("  \n").view().trim().width(), 0);
+        assert!(TextBlock::new("").view().split_columns().is_empty());
+    }
+}
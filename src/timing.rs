@@ -0,0 +1,342 @@
+//! Scoped timers that record into a per-thread span tree
+//!
+//! With a [`CountingAllocator`](crate::memory::CountingAllocator) installed,
+//! each span also records what was allocated while it ran.
+
+use std::cell::{Cell, RefCell};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::json;
+use crate::memory::{AllocScope, AllocStats};
+
+/// A scoped timer that measures execution time
+///
+/// The timer starts when created and, when dropped, records the elapsed time
+/// as a span nested under whichever timer was running when it started. The
+/// collected tree can be retrieved with [`take_spans`] and rendered with
+/// [`render_report`] or [`spans_to_json`]. Call [`set_print_on_drop`] to also
+/// print each timer as it finishes.
+///
+/// # Example
+/// ```
+/// use aoc2025::Timer;
+///
+/// fn some_function() {
+///     let _timer = Timer::new("some_function");
+///     // ... your code here ...
+/// } // Timer automatically records elapsed time here
+/// ```
+pub struct Timer {
+    name: String,
+    start: Instant,
+    /// Depth of this timer's span on the open-span stack, if it is recorded
+    depth: Option<usize>,
+    /// Allocation counting for the span, if it is recorded and counting is on
+    alloc: Option<AllocScope>,
+}
+
+impl Timer {
+    /// Create a new timer with the given name
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        let depth = if is_silenced() {
+            None
+        } else {
+            Some(RECORDER.with(|recorder| recorder.borrow_mut().open(&name)))
+        };
+        // Started after opening the span so its own bookkeeping isn't counted
+        let alloc = depth.and_then(|_| AllocScope::begin());
+        Self {
+            name,
+            start: Instant::now(),
+            depth,
+            alloc,
+        }
+    }
+
+    /// The name the timer was created with
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Time elapsed since the timer was created
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        let alloc = self.alloc.take().map(AllocScope::finish);
+        if let Some(depth) = self.depth {
+            RECORDER.with(|recorder| recorder.borrow_mut().close(depth, elapsed, alloc));
+        }
+        if PRINT_ON_DROP.load(Ordering::Relaxed) && !is_silenced() {
+            println!("⏱️  {} took: {:?}", self.name, elapsed);
+        }
+    }
+}
+
+/// A finished timer and the timers that ran inside it
+///
+/// Sibling timers with the same name are merged, so a timer inside a loop
+/// shows up once with its total time and how often it ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub name: String,
+    /// Total time across all runs
+    pub elapsed: Duration,
+    /// How many timers were merged into this span
+    pub count: u32,
+    /// What the span allocated across all runs, when allocations are counted
+    pub alloc: Option<AllocStats>,
+    pub children: Vec<Span>,
+}
+
+impl Span {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            elapsed: Duration::ZERO,
+            count: 1,
+            alloc: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Add `span` to `spans`, merging it into a sibling with the same name
+fn merge_span(spans: &mut Vec<Span>, span: Span) {
+    match spans.iter_mut().find(|s| s.name == span.name) {
+        Some(existing) => {
+            existing.elapsed += span.elapsed;
+            existing.count += span.count;
+            existing.alloc = match (existing.alloc, span.alloc) {
+                (Some(mut total), Some(more)) => {
+                    total.merge(more);
+                    Some(total)
+                }
+                (total, more) => total.or(more),
+            };
+            for child in span.children {
+                merge_span(&mut existing.children, child);
+            }
+        }
+        None => spans.push(span),
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    /// Finished top-level spans
+    roots: Vec<Span>,
+    /// Spans whose timers are still running, outermost first
+    open: Vec<Span>,
+}
+
+impl Recorder {
+    fn open(&mut self, name: &str) -> usize {
+        self.open.push(Span::new(name));
+        self.open.len() - 1
+    }
+
+    fn close(&mut self, depth: usize, elapsed: Duration, alloc: Option<AllocStats>) {
+        // Timers dropped out of order close any spans still open inside them
+        while self.open.len() > depth + 1 {
+            let orphan = self.open.pop().unwrap();
+            self.attach(orphan);
+        }
+        if self.open.len() == depth + 1 {
+            let mut span = self.open.pop().unwrap();
+            span.elapsed = elapsed;
+            span.alloc = alloc;
+            self.attach(span);
+        }
+    }
+
+    fn attach(&mut self, span: Span) {
+        match self.open.last_mut() {
+            Some(parent) => merge_span(&mut parent.children, span),
+            None => merge_span(&mut self.roots, span),
+        }
+    }
+}
+
+thread_local! {
+    static RECORDER: RefCell<Recorder> = RefCell::new(Recorder::default());
+    static TIMERS_SILENCED: Cell<u32> = const { Cell::new(0) };
+}
+
+static PRINT_ON_DROP: AtomicBool = AtomicBool::new(false);
+
+/// Also print every timer as it is dropped (off by default)
+pub fn set_print_on_drop(enabled: bool) {
+    PRINT_ON_DROP.store(enabled, Ordering::Relaxed);
+}
+
+/// Take the spans finished so far on this thread, leaving the tree empty
+pub fn take_spans() -> Vec<Span> {
+    RECORDER.with(|recorder| std::mem::take(&mut recorder.borrow_mut().roots))
+}
+
+fn is_silenced() -> bool {
+    TIMERS_SILENCED.with(|silenced| silenced.get()) > 0
+}
+
+/// Run `f` without any [`Timer`] on this thread printing or recording spans
+///
+/// Used by the benchmark harness so repeated iterations don't flood stdout.
+pub fn with_timers_silenced<R>(f: impl FnOnce() -> R) -> R {
+    struct Unsilence;
+    impl Drop for Unsilence {
+        fn drop(&mut self) {
+            TIMERS_SILENCED.with(|silenced| silenced.set(silenced.get() - 1));
+        }
+    }
+
+    TIMERS_SILENCED.with(|silenced| silenced.set(silenced.get() + 1));
+    let _unsilence = Unsilence;
+    f()
+}
+
+/// Render spans as an indented report, one line per span
+pub fn render_report(spans: &[Span]) -> String {
+    fn width(spans: &[Span], indent: usize) -> usize {
+        spans
+            .iter()
+            .map(|s| (indent + s.name.chars().count()).max(width(&s.children, indent + 2)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn render(out: &mut String, spans: &[Span], indent: usize, width: usize) {
+        for span in spans {
+            let label = format!("{:indent$}{}", "", span.name, indent = indent);
+            let elapsed = format!("{:?}", span.elapsed);
+            let _ = write!(out, "⏱️  {:<width$}  {:>10}", label, elapsed, width = width);
+            if span.count > 1 {
+                let _ = write!(out, "  ×{}", span.count);
+            }
+            if let Some(alloc) = span.alloc {
+                let _ = write!(out, "  {}", alloc);
+            }
+            out.push('\n');
+            render(out, &span.children, indent + 2, width);
+        }
+    }
+
+    let mut out = String::new();
+    render(&mut out, spans, 0, width(spans, 0));
+    out
+}
+
+/// Export spans as a JSON array of `{name, elapsed_ns, count, children}`
+/// objects, plus `alloc: {count, bytes, peak}` when allocations were counted
+pub fn spans_to_json(spans: &[Span]) -> String {
+    let items: Vec<String> = spans
+        .iter()
+        .map(|span| {
+            let alloc = span.alloc.map_or(String::new(), |alloc| {
+                format!(
+                    ",\"alloc\":{{\"count\":{},\"bytes\":{},\"peak\":{}}}",
+                    alloc.count, alloc.bytes, alloc.peak
+                )
+            });
+            format!(
+                "{{\"name\":{},\"elapsed_ns\":{},\"count\":{}{},\"children\":{}}}",
+                json::string(&span.name),
+                span.elapsed.as_nanos(),
+                span.count,
+                alloc,
+                spans_to_json(&span.children)
+            )
+        })
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(spans: &[Span]) -> Vec<(&str, u32)> {
+        spans.iter().map(|s| (s.name.as_str(), s.count)).collect()
+    }
+
+    #[test]
+    fn test_nested_timers_build_tree() {
+        take_spans();
+        {
+            let _outer = Timer::new("Part 1");
+            {
+                let _parse = Timer::new("Parse");
+            }
+            for _ in 0..3 {
+                let _step = Timer::new("Step");
+            }
+        }
+        let _ = Timer::new("Part 2");
+
+        let spans = take_spans();
+        assert_eq!(names(&spans), vec![("Part 1", 1), ("Part 2", 1)]);
+        assert_eq!(names(&spans[0].children), vec![("Parse", 1), ("Step", 3)]);
+        assert!(spans[0].elapsed >= spans[0].children[1].elapsed);
+        assert!(take_spans().is_empty());
+    }
+
+    #[test]
+    fn test_silenced_timers_are_not_recorded() {
+        take_spans();
+        with_timers_silenced(|| {
+            let _timer = Timer::new("Hidden");
+        });
+        assert!(take_spans().is_empty());
+    }
+
+    #[test]
+    fn test_spans_record_allocations() {
+        // The library tests install a counting allocator (see `memory`)
+        take_spans();
+        {
+            let _outer = Timer::new("Outer");
+            let _data = std::hint::black_box(vec![0_u8; 4096]);
+        }
+        let spans = take_spans();
+        let alloc = spans[0].alloc.expect("allocations are counted");
+        assert!(alloc.count >= 1 && alloc.bytes >= 4096 && alloc.peak >= 4096);
+        assert!(render_report(&spans).contains(" allocs, "));
+        assert!(spans_to_json(&spans).contains(",\"alloc\":{\"count\":"));
+    }
+
+    #[test]
+    fn test_render_and_json() {
+        let spans = vec![Span {
+            name: "Part 1".to_string(),
+            elapsed: Duration::from_micros(5),
+            count: 1,
+            alloc: None,
+            children: vec![Span {
+                name: "Step \"a\"".to_string(),
+                elapsed: Duration::from_micros(3),
+                count: 2,
+                alloc: None,
+                children: Vec::new(),
+            }],
+        }];
+        let report = render_report(&spans);
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("⏱️  Part 1 "));
+        assert!(lines[1].starts_with("⏱️    Step \"a\""));
+        assert!(lines[1].ends_with("3µs  ×2"));
+
+        assert_eq!(
+            spans_to_json(&spans),
+            "[{\"name\":\"Part 1\",\"elapsed_ns\":5000,\"count\":1,\"children\":\
+             [{\"name\":\"Step \\\"a\\\"\",\"elapsed_ns\":3000,\"count\":2,\"children\":[]}]}]"
+        );
+    }
+}
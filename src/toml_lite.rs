@@ -0,0 +1,232 @@
+//! Reader for the small subset of TOML used by the local data files
+//!
+//! Supports `[table]` headers, `key = value` entries whose values are
+//! integers, basic strings or multi-line literal strings (`'''`), and `#`
+//! comments.
+
+/// A value on the right-hand side of an entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Integer(i64),
+    String(String),
+}
+
+/// One meaningful line of a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line<'a> {
+    Table(&'a str),
+    Entry(&'a str, Value),
+}
+
+/// Parse every non-blank line, returning `(line number, line)` pairs
+///
+/// Errors carry the 1-based line number and a message.
+pub fn parse(text: &str) -> Result<Vec<(usize, Line<'_>)>, (usize, String)> {
+    let mut lines = Vec::new();
+    let mut raw_lines = text.lines().enumerate();
+    while let Some((idx, raw_line)) = raw_lines.next() {
+        let line_no = idx + 1;
+        let line = strip_comment(raw_line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .ok_or_else(|| (line_no, "unterminated table header".to_string()))?;
+            lines.push((line_no, Line::Table(name.trim())));
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| (line_no, "expected `key = value`".to_string()))?;
+        let value = match value.trim().strip_prefix(LITERAL_DELIMITER) {
+            Some(rest) => Value::String(
+                parse_multiline_literal(rest, &mut raw_lines)
+                    .map_err(|message| (line_no, message))?,
+            ),
+            None => parse_value(value.trim()).map_err(|message| (line_no, message))?,
+        };
+        lines.push((line_no, Line::Entry(key.trim(), value)));
+    }
+    Ok(lines)
+}
+
+/// Quote and escape a string as a basic TOML string
+pub fn quote(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    )
+}
+
+/// Write a string as a multi-line literal string, so it reads as it would in
+/// a text file
+///
+/// Falls back to [`quote`] for strings a literal can't hold.
+pub fn quote_multiline(value: &str) -> String {
+    if value.contains(LITERAL_DELIMITER) || value.contains('\r') {
+        quote(value)
+    } else {
+        // The line break right after the opening delimiter is not part of the value
+        format!("{}\n{}{}", LITERAL_DELIMITER, value, LITERAL_DELIMITER)
+    }
+}
+
+const LITERAL_DELIMITER: &str = "'''";
+
+/// Read a multi-line literal string whose opening line continues with
+/// `first`, taking further lines from `raw_lines` up to the closing delimiter
+fn parse_multiline_literal<'a>(
+    first: &str,
+    raw_lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<String, String> {
+    let mut text = String::new();
+    let mut current = first;
+    let mut on_first_line = true;
+    loop {
+        if let Some(end) = current.find(LITERAL_DELIMITER) {
+            let trailing = &current[end + LITERAL_DELIMITER.len()..];
+            if !strip_comment(trailing).trim().is_empty() {
+                return Err(format!("unexpected '{}' after string", trailing.trim()));
+            }
+            text.push_str(&current[..end]);
+            return Ok(text);
+        }
+        if !(on_first_line && current.is_empty()) {
+            text.push_str(current);
+            text.push('\n');
+        }
+        on_first_line = false;
+        current = match raw_lines.next() {
+            Some((_, line)) => line,
+            None => return Err("unterminated multi-line string".to_string()),
+        };
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    // A '#' inside a quoted string is not a comment
+    let mut in_string = false;
+    let mut in_literal = false;
+    let mut chars = line.char_indices();
+    while let Some((idx, c)) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' if !in_literal => in_string = !in_string,
+            '\'' if !in_string => in_literal = !in_literal,
+            '#' if !in_string && !in_literal => return &line[..idx],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_value(value: &str) -> Result<Value, String> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let inner = quoted
+            .strip_suffix('"')
+            .ok_or_else(|| "unterminated string".to_string())?;
+        let mut text = String::new();
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some(escaped @ ('\\' | '"')) => text.push(escaped),
+                    Some('n') => text.push('\n'),
+                    other => {
+                        return Err(format!("unsupported escape '\\{}'", other.unwrap_or(' ')))
+                    }
+                },
+                c => text.push(c),
+            }
+        }
+        Ok(Value::String(text))
+    } else {
+        value
+            .replace('_', "")
+            .parse::<i64>()
+            .map(Value::Integer)
+            .map_err(|_| format!("expected an integer or a quoted string, found '{}'", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let text = "# header\n[day01]\npart1 = 1_000 # comment\n\nname = \"a#b \\\"c\\\"\"\n";
+        assert_eq!(
+            parse(text).unwrap(),
+            vec![
+                (2, Line::Table("day01")),
+                (3, Line::Entry("part1", Value::Integer(1000))),
+                (
+                    5,
+                    Line::Entry("name", Value::String("a#b \"c\"".to_string()))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("[day01").unwrap_err().0, 1);
+        assert_eq!(parse("\nkey").unwrap_err().0, 2);
+        assert_eq!(parse("key = bare").unwrap_err().0, 1);
+        assert_eq!(parse("key = \"open").unwrap_err().0, 1);
+    }
+
+    #[test]
+    fn test_quote_roundtrip() {
+        let original = "say \"hi\"\\\n";
+        let text = format!("key = {}", quote(original));
+        assert_eq!(
+            parse(&text).unwrap(),
+            vec![(1, Line::Entry("key", Value::String(original.to_string())))]
+        );
+    }
+
+    #[test]
+    fn test_multiline_literal() {
+        let text =
+            "a = '''\nL68\n  # kept \\n\n'''\nb = '''x'''  # comment\nc = '''\ntrailing  '''\n";
+        assert_eq!(
+            parse(text).unwrap(),
+            vec![
+                (
+                    1,
+                    Line::Entry("a", Value::String("L68\n  # kept \\n\n".to_string()))
+                ),
+                (5, Line::Entry("b", Value::String("x".to_string()))),
+                (6, Line::Entry("c", Value::String("trailing  ".to_string()))),
+            ]
+        );
+        assert_eq!(parse("\na = '''\nopen\n").unwrap_err().0, 2);
+        assert_eq!(parse("a = '''x''' y").unwrap_err().0, 1);
+    }
+
+    #[test]
+    fn test_quote_multiline_roundtrip() {
+        for original in [
+            "L68\nR48\n",
+            "\nstarts blank",
+            "ends with space ",
+            "has ''' in it",
+            "",
+        ] {
+            let text = format!("key = {}\n", quote_multiline(original));
+            assert_eq!(
+                parse(&text).unwrap(),
+                vec![(1, Line::Entry("key", Value::String(original.to_string())))]
+            );
+        }
+    }
+}
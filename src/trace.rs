@@ -0,0 +1,443 @@
+//! Structured tracing for solvers
+//!
+//! Solvers report what they are doing with [`trace_event!`](crate::trace_event),
+//! as a named event with typed fields rather than a formatted message:
+//!
+//! ```
+//! use aoc2025::trace::Level;
+//! use aoc2025::trace_event;
+//!
+//! let position = 82;
+//! trace_event!(Level::Debug, "step", direction = 'L', distance = 68, position = position);
+//! ```
+//!
+//! Every event has a [`Level`] and a target. The target defaults to the last
+//! segment of the module that emitted it, so events from
+//! `aoc2025::days::day01` have the target `day01`. A [`TraceFilter`] picks
+//! which events are shown, from directives like `day01=debug,info`; install
+//! one with [`set_filter`]. Shown events are written to stderr, or collected
+//! with [`capture`] in tests.
+//!
+//! With tracing off, an event costs one atomic load and a comparison: its
+//! fields are not evaluated.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::RwLock;
+
+/// How much detail an event carries, from least to most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Level {
+    /// Results worth seeing on every traced run, such as totals
+    Info = 1,
+    /// One event per step of the solution
+    Debug = 2,
+    /// Inner-loop detail
+    Trace = 3,
+}
+
+impl Level {
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+
+    /// Parse a level name; `off` gives `Ok(None)`
+    pub fn parse(name: &str) -> Result<Option<Level>, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "off" => Ok(None),
+            "info" => Ok(Some(Level::Info)),
+            "debug" => Ok(Some(Level::Debug)),
+            "trace" => Ok(Some(Level::Trace)),
+            _ => Err(format!(
+                "Invalid trace level '{}': expected off, info, debug or trace",
+                name
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The value of an event field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(i64),
+    UInt(u64),
+    Bool(bool),
+    Char(char),
+    Text(String),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::UInt(n) => write!(f, "{}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Char(c) => write!(f, "{}", c),
+            Value::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_value_from {
+    ($variant:ident as $as:ty: $($t:ty),+) => {$(
+        impl From<$t> for Value {
+            fn from(value: $t) -> Self {
+                Value::$variant(value as $as)
+            }
+        }
+    )+};
+}
+
+impl_value_from!(Int as i64: i32, i64, isize);
+impl_value_from!(UInt as u64: u8, u32, u64, usize);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        Value::Char(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+/// One thing a solver reported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    pub target: &'static str,
+    pub level: Level,
+    pub name: &'static str,
+    pub fields: Vec<(&'static str, Value)>,
+}
+
+impl Event {
+    /// The value of the field called `key`, if the event has one
+    pub fn field(&self, key: &str) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Written as `name key=value ...`
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)?;
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Which events to show: a default level plus overrides for named targets
+///
+/// Parsed from comma-separated directives, where later ones win:
+/// - `debug` sets the level for every target
+/// - `day01=trace` sets the level for one target
+/// - a bare target such as `day01` shows everything from it
+///
+/// Any level can be `off`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceFilter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+}
+
+impl TraceFilter {
+    /// A filter that shows nothing
+    pub const fn off() -> Self {
+        Self {
+            default: None,
+            targets: Vec::new(),
+        }
+    }
+
+    /// A filter that shows events up to `level` from every target
+    pub fn all(level: Level) -> Self {
+        Self {
+            default: Some(level),
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut filter = Self::off();
+        filter.add(spec)?;
+        Ok(filter)
+    }
+
+    /// Apply more directives on top of the existing ones
+    pub fn add(&mut self, spec: &str) -> Result<(), String> {
+        for directive in spec.split(',').map(str::trim) {
+            if directive.is_empty() {
+                continue;
+            }
+            match directive.split_once('=') {
+                Some((target, level)) => {
+                    let target = target.trim();
+                    if target.is_empty() {
+                        return Err(format!("Missing target in trace directive '{}'", directive));
+                    }
+                    self.set_target(target, Level::parse(level.trim())?);
+                }
+                None => match Level::parse(directive) {
+                    Ok(level) => {
+                        self.default = level;
+                        self.targets.clear();
+                    }
+                    Err(_) => self.set_target(directive, Some(Level::Trace)),
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn set_target(&mut self, target: &str, level: Option<Level>) {
+        self.targets.retain(|(name, _)| name != target);
+        self.targets.push((target.to_string(), level));
+    }
+
+    /// The most detailed level shown for `target`, or `None` if it is off
+    pub fn level_for(&self, target: &str) -> Option<Level> {
+        self.targets
+            .iter()
+            .find(|(name, _)| name == target)
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        self.level_for(target).is_some_and(|max| level <= max)
+    }
+
+    /// The most detailed level shown for any target
+    pub fn max_level(&self) -> Option<Level> {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .chain([self.default])
+            .max()
+            .flatten()
+    }
+
+    pub fn is_off(&self) -> bool {
+        self.max_level().is_none()
+    }
+}
+
+/// The filter set with [`set_filter`]
+static FILTER: RwLock<TraceFilter> = RwLock::new(TraceFilter::off());
+/// `FILTER.max_level()` as a number (0 when off), checked before anything else
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+/// How many [`capture`] calls are running, on any thread
+static CAPTURES: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The filter and events of the innermost [`capture`] on this thread
+    static CAPTURE: RefCell<Option<(TraceFilter, Vec<Event>)>> = const { RefCell::new(None) };
+}
+
+/// Install the filter that decides which events are written to stderr
+pub fn set_filter(filter: TraceFilter) {
+    MAX_LEVEL.store(
+        filter.max_level().map_or(0, |level| level as u8),
+        Ordering::Relaxed,
+    );
+    *FILTER.write().unwrap_or_else(|err| err.into_inner()) = filter;
+}
+
+/// The filter currently installed
+pub fn filter() -> TraceFilter {
+    FILTER.read().unwrap_or_else(|err| err.into_inner()).clone()
+}
+
+/// Whether any target could want events at `level`; the cheap first check
+#[inline]
+pub fn level_enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed) || CAPTURES.load(Ordering::Relaxed) > 0
+}
+
+/// Whether an event from `target` at `level` would be shown
+#[inline]
+pub fn enabled(target: &str, level: Level) -> bool {
+    level_enabled(level) && enabled_slow(target, level)
+}
+
+fn enabled_slow(target: &str, level: Level) -> bool {
+    let captured = CAPTURE.with(|capture| {
+        capture
+            .borrow()
+            .as_ref()
+            .map(|(filter, _)| filter.enabled(target, level))
+    });
+    captured.unwrap_or_else(|| {
+        FILTER
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .enabled(target, level)
+    })
+}
+
+/// The default target for events emitted from `module_path`: its last segment
+pub fn target_of(module_path: &'static str) -> &'static str {
+    module_path.rsplit("::").next().unwrap_or(module_path)
+}
+
+/// Record an event that passed [`enabled`]
+///
+/// Inside [`capture`] the event is collected; otherwise it goes to stderr.
+pub fn emit(event: Event) {
+    let event = CAPTURE.with(|capture| match capture.borrow_mut().as_mut() {
+        Some((_, events)) => {
+            events.push(event);
+            None
+        }
+        None => Some(event),
+    });
+    if let Some(event) = event {
+        eprintln!("[{} {}] {}", event.target, event.level, event);
+    }
+}
+
+/// Run `f` with `filter` applied to this thread's events, and collect the
+/// events it lets through instead of printing them
+///
+/// The global filter is ignored while capturing.
+pub fn capture<R>(filter: TraceFilter, f: impl FnOnce() -> R) -> (R, Vec<Event>) {
+    /// Restores the enclosing capture, even if `f` panics
+    struct Restore(Option<(TraceFilter, Vec<Event>)>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CAPTURE.with(|capture| *capture.borrow_mut() = self.0.take());
+            CAPTURES.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    CAPTURES.fetch_add(1, Ordering::Relaxed);
+    let restore = Restore(CAPTURE.with(|capture| capture.replace(Some((filter, Vec::new())))));
+    let result = f();
+    let events = CAPTURE.with(|capture| capture.borrow_mut().take().map(|(_, events)| events));
+    drop(restore);
+    (result, events.unwrap_or_default())
+}
+
+/// Emit a structured trace event
+///
+/// Takes a [`Level`], an event name and `key = value` fields, where each
+/// value converts into a [`Value`]. The target is the emitting module's
+/// name unless given with `target: "name",` first. Fields are only
+/// evaluated when the event is enabled.
+#[macro_export]
+macro_rules! trace_event {
+    (target: $target:expr, $level:expr, $name:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        let level: $crate::trace::Level = $level;
+        let target: &'static str = $target;
+        if $crate::trace::enabled(target, level) {
+            $crate::trace::emit($crate::trace::Event {
+                target,
+                level,
+                name: $name,
+                fields: vec![$((stringify!($key), $crate::trace::Value::from($value))),*],
+            });
+        }
+    }};
+    ($level:expr, $name:literal $(, $key:ident = $value:expr)* $(,)?) => {{
+        let level: $crate::trace::Level = $level;
+        if $crate::trace::level_enabled(level) {
+            $crate::trace_event!(
+                target: $crate::trace::target_of(module_path!()),
+                level,
+                $name
+                $(, $key = $value)*
+            );
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filter() {
+        let filter = TraceFilter::parse("info, day01=trace,day03").unwrap();
+        assert_eq!(filter.level_for("day01"), Some(Level::Trace));
+        assert_eq!(filter.level_for("day03"), Some(Level::Trace));
+        assert_eq!(filter.level_for("day05"), Some(Level::Info));
+        assert_eq!(filter.max_level(), Some(Level::Trace));
+        assert!(filter.enabled("day05", Level::Info));
+        assert!(!filter.enabled("day05", Level::Debug));
+
+        let mut filter = TraceFilter::parse("day01=debug").unwrap();
+        assert_eq!(filter.level_for("day02"), None);
+        filter.add("day01=off").unwrap();
+        assert!(filter.is_off());
+        // A bare level resets earlier per-target directives
+        filter.add("day02=trace,debug").unwrap();
+        assert_eq!(filter, TraceFilter::all(Level::Debug));
+
+        assert!(TraceFilter::parse("day01=loud").is_err());
+        assert!(TraceFilter::parse("=debug").is_err());
+        assert!(TraceFilter::parse("").unwrap().is_off());
+    }
+
+    #[test]
+    fn test_capture() {
+        let filter = TraceFilter::parse("tests=debug").unwrap();
+        let (answer, events) = capture(filter, || {
+            trace_event!(Level::Debug, "step", position = 82, hit = false);
+            trace_event!(Level::Trace, "detail", skipped = true);
+            trace_event!(target: "day01", Level::Info, "elsewhere");
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].target, "tests");
+        assert_eq!(events[0].field("position"), Some(&Value::Int(82)));
+        assert_eq!(events[0].to_string(), "step position=82 hit=false");
+    }
+
+    #[test]
+    fn test_fields_not_evaluated_when_off() {
+        let mut evaluated = false;
+        let (_, events) = capture(TraceFilter::off(), || {
+            trace_event!(
+                Level::Info,
+                "lazy",
+                value = {
+                    evaluated = true;
+                    1
+                }
+            );
+        });
+        assert!(events.is_empty());
+        assert!(!evaluated);
+    }
+}
@@ -0,0 +1,296 @@
+//! Re-run a day whenever its input or examples change
+//!
+//! A [`Watcher`] polls a set of files for changes to their size or
+//! modification time; no platform file-notification API is needed. Each
+//! re-run is a [`Round`]: the day's answers on its input plus a check of
+//! every example in its fixtures file. [`render_round`] prints a round next
+//! to the previous one, so an edit that changes an answer stands out.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::fixtures::Fixtures;
+use crate::summary::{run_day, DayReport, Status};
+use crate::{Answer, AocError, Day, Part};
+
+/// What identifies one version of a file: its modification time and size
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Polls files for changes
+///
+/// A file that doesn't exist yet is watched too, and counts as changed when
+/// it appears (or disappears).
+#[derive(Debug, Clone)]
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    /// Start watching `paths` in their current state
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let stamp = stamp(&path);
+                (path, stamp)
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// The files that changed since the last call (or since [`new`](Self::new))
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, last) in &mut self.files {
+            let now = stamp(path);
+            if now != *last {
+                *last = now;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+}
+
+/// How one example from the fixtures file fared
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExampleCheck {
+    /// The solver gave the puzzle's answer
+    Pass,
+    /// The solver gave a different answer, or `None` if it panicked
+    Fail {
+        expected: Answer,
+        actual: Option<Answer>,
+    },
+    /// The fixture doesn't record an answer to compare with
+    Unchecked { actual: Option<Answer> },
+}
+
+/// The outcome of running a day once
+#[derive(Debug, Clone)]
+pub struct Round {
+    /// The run on the real input
+    pub report: DayReport,
+    /// Each selected part that has an example, or the error that kept the
+    /// fixtures file from loading
+    pub examples: Result<Vec<(Part, ExampleCheck)>, String>,
+}
+
+/// Solve `parts` of `day` on `input` and on the examples in `fixtures`,
+/// catching panics
+///
+/// A missing fixtures file means there are no examples, not an error.
+pub fn run_round(
+    day: &Day,
+    input: Result<String, AocError>,
+    fixtures: Result<Fixtures, AocError>,
+    parts: &[Part],
+) -> Round {
+    let report = run_day(day, input, parts);
+
+    let examples = match fixtures {
+        Ok(fixtures) => Ok(parts
+            .iter()
+            .filter_map(|&part| {
+                let example = fixtures.get(part)?;
+                let run = run_day(day, Ok(example.input.clone()), &[part]);
+                let actual = run.part(part).and_then(|p| p.answer.clone());
+                let check = match &example.answer {
+                    Some(expected) if actual.as_ref() == Some(expected) => ExampleCheck::Pass,
+                    Some(expected) => ExampleCheck::Fail {
+                        expected: expected.clone(),
+                        actual,
+                    },
+                    None => ExampleCheck::Unchecked { actual },
+                };
+                Some((part, check))
+            })
+            .collect()),
+        Err(AocError::NotFound { .. }) => Ok(Vec::new()),
+        Err(err) => Err(err.to_string()),
+    };
+
+    Round { report, examples }
+}
+
+/// Render a round, marking answers that differ from the `previous` round
+pub fn render_round(round: &Round, previous: Option<&Round>) -> String {
+    let shown = |answer: Option<&Answer>| answer.map_or("-".to_string(), Answer::to_string);
+
+    let mut out = String::new();
+    let report = &round.report;
+    for part in &report.parts {
+        let answer = part.answer.as_ref();
+        let _ = write!(out, "{}: {}", part.part.label(), shown(answer));
+        let before = previous.and_then(|previous| previous.report.part(part.part));
+        match before.map(|before| before.answer.as_ref()) {
+            Some(old) if old != answer => {
+                let _ = write!(out, "  (was {})", shown(old));
+            }
+            Some(_) => out.push_str("  (unchanged)"),
+            None => {}
+        }
+        let _ = writeln!(out, "  [{:.2?}]", part.elapsed);
+    }
+    if report.status != Status::Ok {
+        let _ = writeln!(out, "❌ {}", report.status);
+    }
+
+    match &round.examples {
+        Ok(examples) => {
+            for (part, check) in examples {
+                let _ = write!(out, "Example {}: ", part.label().to_lowercase());
+                let _ = match check {
+                    ExampleCheck::Pass => writeln!(out, "✅ pass"),
+                    ExampleCheck::Fail { expected, actual } => {
+                        writeln!(
+                            out,
+                            "❌ expected {}, got {}",
+                            expected,
+                            shown(actual.as_ref())
+                        )
+                    }
+                    ExampleCheck::Unchecked { actual } => writeln!(
+                        out,
+                        "❔ no expected answer (got {})",
+                        shown(actual.as_ref())
+                    ),
+                };
+            }
+        }
+        Err(err) => {
+            let _ = writeln!(out, "❌ examples: {}", err);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Example;
+    use crate::{Context, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sum";
+        type Parsed = Vec<i64>;
+
+        fn parse(input: &str) -> Self::Parsed {
+            input.lines().map(|l| l.parse().unwrap()).collect()
+        }
+
+        fn part1(numbers: &Self::Parsed, _ctx: &Context) -> Answer {
+            numbers.iter().sum::<i64>().into()
+        }
+
+        fn part2(numbers: &Self::Parsed, _ctx: &Context) -> Answer {
+            numbers.iter().product::<i64>().into()
+        }
+    }
+
+    static SUM: Day = Day::new::<Sum>();
+
+    fn fixtures(part1: i64, part2: Option<i64>) -> Fixtures {
+        let mut fixtures = Fixtures::default();
+        let example = |answer: Option<i64>| Example {
+            input: "2\n3\n".to_string(),
+            answer: answer.map(Answer::Number),
+        };
+        fixtures.insert(Part::One, example(Some(part1)));
+        fixtures.insert(Part::Two, example(part2));
+        fixtures
+    }
+
+    #[test]
+    fn test_watcher_sees_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (file, missing) = (dir.join("input.txt"), dir.join("later.txt"));
+        fs::write(&file, "1").unwrap();
+
+        let mut watcher = Watcher::new([file.clone(), missing.clone()]);
+        assert!(watcher.changed().is_empty());
+        // A different size is a change even within the timestamp resolution
+        fs::write(&file, "12").unwrap();
+        fs::write(&missing, "").unwrap();
+        assert_eq!(watcher.changed(), vec![file, missing.clone()]);
+        assert!(watcher.changed().is_empty());
+        fs::remove_file(&missing).unwrap();
+        assert_eq!(watcher.changed(), vec![missing]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rounds_diff_answers_and_check_examples() {
+        let first = run_round(
+            &SUM,
+            Ok("1\n4".to_string()),
+            Ok(fixtures(5, None)),
+            &Part::ALL,
+        );
+        assert_eq!(
+            first.examples,
+            Ok(vec![
+                (Part::One, ExampleCheck::Pass),
+                (
+                    Part::Two,
+                    ExampleCheck::Unchecked {
+                        actual: Some(Answer::Number(6))
+                    }
+                ),
+            ])
+        );
+        let text = render_round(&first, None);
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("Part 1: 5  ["));
+        assert_eq!(lines[2], "Example part 1: ✅ pass");
+        assert_eq!(lines[3], "Example part 2: ❔ no expected answer (got 6)");
+
+        let second = run_round(
+            &SUM,
+            Ok("1\n5".to_string()),
+            Ok(fixtures(4, Some(6))),
+            &Part::ALL,
+        );
+        let text = render_round(&second, Some(&first));
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("Part 1: 6  (was 5)  ["));
+        assert!(lines[1].starts_with("Part 2: 5  (was 4)  ["));
+        assert_eq!(lines[2], "Example part 1: ❌ expected 4, got 5");
+        assert_eq!(lines[3], "Example part 2: ✅ pass");
+
+        let third = run_round(
+            &SUM,
+            Ok("1\n5".to_string()),
+            Ok(fixtures(4, Some(6))),
+            &[Part::One],
+        );
+        assert!(render_round(&third, Some(&second)).starts_with("Part 1: 6  (unchanged)  ["));
+    }
+
+    #[test]
+    fn test_round_without_input_or_fixtures() {
+        let missing = |path: &str| AocError::NotFound {
+            path: PathBuf::from(path),
+        };
+        let round = run_round(
+            &SUM,
+            Err(missing("Input/day01.txt")),
+            Err(missing("fixtures/day01.toml")),
+            &Part::ALL,
+        );
+        assert_eq!(round.report.status, Status::MissingInput);
+        assert_eq!(round.examples, Ok(Vec::new()));
+        assert_eq!(render_round(&round, None), "❌ missing input\n");
+    }
+}